@@ -1,36 +1,85 @@
 use maplit::hashmap;
 use std::collections::HashMap;
+use table_rs::core::data::SharedRows;
 use table_rs::yew::table::Table;
-use table_rs::yew::types::Column;
-use web_sys::window;
+use table_rs::yew::types::{Column, SortKey};
 use web_sys::js_sys;
+use web_sys::{window, Performance};
 use yew::prelude::*;
 
+fn user(i: usize) -> HashMap<&'static str, String> {
+    hashmap! {
+        "id" => i.to_string(),
+        "name" => format!("User {}", i),
+        "email" => format!("user{}@example.com", i),
+        "age" => (18 + (js_sys::Math::random() * 80.0) as u8).to_string(),
+        "registered" => js_sys::Date::new_0().to_string().into()
+    }
+}
+
+fn performance() -> Performance {
+    window()
+        .and_then(|w| w.performance())
+        .expect("performance should be available")
+}
+
 #[function_component(LandingPage)]
 pub fn landing_page() -> Html {
-    let data = use_state(|| None::<Vec<HashMap<&'static str, String>>>);
+    // Kept across renders, so that the table only sorts again when the data or the sort change.
+    let data = use_state(|| None::<SharedRows<HashMap<&'static str, String>>>);
     let loading = data.is_none();
     let data_val = (*data).clone();
 
-    use_effect_with((), move |_| {
+    // Sorting is timed from the header click until the sorted rows are rendered; the first sort
+    // of a column builds its index, flipping its direction then reverses it.
+    let sort = use_state(Vec::<SortKey>::new);
+    let on_sort_change = {
+        let sort = sort.clone();
+        Callback::from(move |keys: Vec<SortKey>| {
+            performance().mark("sort-start").ok();
+            sort.set(keys);
+        })
+    };
+    use_effect_with((*sort).clone(), |sort| {
+        let performance = performance();
+        if !sort.is_empty() && performance.mark("sort-end").is_ok() {
+            performance
+                .measure_with_start_mark_and_end_mark("Sort", "sort-start", "sort-end")
+                .ok();
+        }
+    });
+
+    // Appended rows are merged into the sort indexes instead of sorting every row again.
+    let appending = use_mut_ref(|| false);
+    let on_append = {
         let data = data.clone();
-        let performance = window()
-            .and_then(|w| w.performance())
-            .expect("performance should be available");
+        let appending = appending.clone();
+        Callback::from(move |_| {
+            let Some(rows) = (*data).clone() else {
+                return;
+            };
+            performance().mark("append-start").ok();
+            *appending.borrow_mut() = true;
+            let start = rows.len() + 1;
+            data.set(Some(rows.appended((start..start + 10_000).map(user))));
+        })
+    };
+    use_effect_with(data_val.as_ref().map_or(0, |rows| rows.len()), move |_| {
+        if appending.replace(false) {
+            let performance = performance();
+            performance.mark("append-end").ok();
+            performance
+                .measure_with_start_mark_and_end_mark("Append", "append-start", "append-end")
+                .ok();
+        }
+    });
+
+    use_effect_with((), move |_| {
+        let performance = performance();
 
         performance.mark("data-gen-start").ok();
 
-        let rows = (1..=1_000_000)
-            .map(|i| {
-                hashmap! {
-                    "id" => i.to_string(),
-                    "name" => format!("User {}", i),
-                    "email" => format!("user{}@example.com", i),
-                    "age" => (18 + (js_sys::Math::random() * 80.0) as u8).to_string(),
-                    "registered" => js_sys::Date::new_0().to_string().into()
-                }
-            })
-            .collect::<Vec<_>>();
+        let rows = (1..=1_000_000).map(user).collect::<Vec<_>>();
 
         performance.mark("data-gen-end").ok();
         performance
@@ -41,7 +90,7 @@ pub fn landing_page() -> Html {
             )
             .ok();
 
-        data.set(Some(rows));
+        data.set(Some(rows.into()));
         || ()
     });
 
@@ -84,6 +133,7 @@ pub fn landing_page() -> Html {
             if loading {
                 <p>{ "Loading table data..." }</p>
             } else {
+                <button onclick={on_append}>{ "Append 10,000 rows" }</button>
                 <Table
                     data={data_val.unwrap()}
                     columns={columns}
@@ -91,6 +141,8 @@ pub fn landing_page() -> Html {
                     search={true}
                     paginate={true}
                     loading={false}
+                    sort={(*sort).clone()}
+                    {on_sort_change}
                 />
             }
         </div>
@@ -0,0 +1,337 @@
+//! Derive macros for [`table-rs`](https://docs.rs/table-rs).
+//!
+//! This crate is re-exported by `table-rs` behind the `derive` feature; depend on that instead of
+//! using it directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, LitStr, PathArguments, Type, parse_macro_input,
+};
+
+/// Derives `table_rs::core::row::TableRow` for a struct with named fields.
+///
+/// Every field becomes a column whose id is the field name and whose header is the field name in
+/// title case. Fields are rendered through their `Display` implementation; `Option` fields yield
+/// an empty cell when `None`.
+///
+/// Integer, float, boolean and string fields (optionally wrapped in `Option`) also provide typed
+/// values, so they sort numerically or logically; other fields sort by their text.
+///
+/// # Attributes
+/// - `#[table(header = "...")]`: header text of the column.
+/// - `#[table(rename = "...")]`: column id, when it must differ from the field name.
+/// - `#[table(sortable)]`: marks the column as sortable.
+/// - `#[table(skip)]`: leaves the field out of the table.
+#[proc_macro_derive(TableRow, attributes(table))]
+pub fn derive_table_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct FieldColumn {
+    ident: syn::Ident,
+    ty: Type,
+    id: String,
+    header: String,
+    sortable: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`TableRow` can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`TableRow` can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut columns = Vec::new();
+    for field in fields {
+        let ident = field.ident.clone().expect("named field");
+        let name = ident.to_string();
+        let mut column = FieldColumn {
+            ident,
+            ty: field.ty.clone(),
+            header: title_case(&name),
+            id: name,
+            sortable: false,
+        };
+        let mut skip = false;
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("table")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("header") {
+                    column.header = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("rename") {
+                    column.id = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("sortable") {
+                    column.sortable = true;
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else {
+                    return Err(meta.error("expected `header`, `rename`, `sortable` or `skip`"));
+                }
+                Ok(())
+            })?;
+        }
+
+        if !skip {
+            columns.push(column);
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let arms = columns.iter().map(|column| {
+        let id = &column.id;
+        let value = cell_value(&column.ident, &column.ty);
+        quote! { #id => #value, }
+    });
+
+    let typed_arms = columns.iter().map(|column| {
+        let id = &column.id;
+        let value = typed_value(&column.ident, &column.ty);
+        quote! { #id => #value, }
+    });
+
+    let metas = columns.iter().map(|column| {
+        let FieldColumn {
+            id,
+            header,
+            sortable,
+            ..
+        } = column;
+        quote! {
+            ::table_rs::core::row::ColumnMeta {
+                id: #id,
+                header: #header,
+                sortable: #sortable,
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::table_rs::core::row::TableRow for #name #ty_generics #where_clause {
+            fn cell(&self, column: &str) -> ::core::option::Option<::std::borrow::Cow<'_, str>> {
+                match column {
+                    #(#arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn value(&self, column: &str) -> ::table_rs::core::value::CellValue {
+                match column {
+                    #(#typed_arms)*
+                    _ => ::table_rs::core::value::CellValue::Null,
+                }
+            }
+
+            fn column_meta() -> ::std::vec::Vec<::table_rs::core::row::ColumnMeta> {
+                ::std::vec![#(#metas),*]
+            }
+        }
+    })
+}
+
+/// Builds the expression returning the cell text of a field, borrowing string fields.
+fn cell_value(ident: &syn::Ident, ty: &Type) -> TokenStream2 {
+    if is_string(ty) {
+        return quote! {
+            ::core::option::Option::Some(::std::borrow::Cow::Borrowed(self.#ident.as_str()))
+        };
+    }
+    if let Type::Reference(reference) = ty
+        && is_str(&reference.elem)
+    {
+        return quote! {
+            ::core::option::Option::Some(::std::borrow::Cow::Borrowed(self.#ident))
+        };
+    }
+    if let Some(inner) = option_inner(ty) {
+        if is_string(inner) {
+            return quote! {
+                self.#ident.as_deref().map(::std::borrow::Cow::Borrowed)
+            };
+        }
+        return quote! {
+            self.#ident
+                .as_ref()
+                .map(|value| ::std::borrow::Cow::Owned(::std::string::ToString::to_string(value)))
+        };
+    }
+    quote! {
+        ::core::option::Option::Some(::std::borrow::Cow::Owned(
+            ::std::string::ToString::to_string(&self.#ident),
+        ))
+    }
+}
+
+/// Builds the expression returning the typed value of a field.
+fn typed_value(ident: &syn::Ident, ty: &Type) -> TokenStream2 {
+    let typed = option_inner(ty).unwrap_or(ty);
+    let is_ref_str = matches!(typed, Type::Reference(reference) if is_str(&reference.elem));
+    if is_primitive(typed) || is_string(typed) || is_ref_str {
+        return quote! {
+            ::table_rs::core::value::CellValue::from(::core::clone::Clone::clone(&self.#ident))
+        };
+    }
+    if option_inner(ty).is_some() {
+        return quote! {
+            self.#ident.as_ref().map_or(::table_rs::core::value::CellValue::Null, |value| {
+                ::table_rs::core::value::CellValue::Text(::std::string::ToString::to_string(value))
+            })
+        };
+    }
+    quote! {
+        ::table_rs::core::value::CellValue::Text(::std::string::ToString::to_string(&self.#ident))
+    }
+}
+
+fn last_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+fn is_string(ty: &Type) -> bool {
+    last_segment(ty).is_some_and(|s| s.ident == "String" && s.arguments.is_none())
+}
+
+fn is_primitive(ty: &Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize", "f32", "f64",
+        "bool",
+    ];
+    last_segment(ty).is_some_and(|s| PRIMITIVES.iter().any(|p| s.ident == p))
+}
+
+fn is_str(ty: &Type) -> bool {
+    last_segment(ty).is_some_and(|s| s.ident == "str")
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let segment = last_segment(ty)?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Turns a `snake_case` field name into a `Title Case` header.
+fn title_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn error(input: DeriveInput) -> String {
+        match expand(input) {
+            Ok(_) => panic!("expected an error"),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn rejects_enums_and_unnamed_fields() {
+        let input = parse_quote! { enum Status { Active, Archived } };
+        assert_eq!(error(input), "`TableRow` can only be derived for structs");
+
+        for input in [
+            parse_quote! { struct Pair(String, u32); },
+            parse_quote! { struct Unit; },
+        ] {
+            assert_eq!(
+                error(input),
+                "`TableRow` can only be derived for structs with named fields"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_and_malformed_attributes() {
+        let input = parse_quote! {
+            struct User {
+                #[table(hidden)]
+                name: String,
+            }
+        };
+        assert_eq!(
+            error(input),
+            "expected `header`, `rename`, `sortable` or `skip`"
+        );
+
+        let input = parse_quote! {
+            struct User {
+                #[table(header = 42)]
+                name: String,
+            }
+        };
+        assert_eq!(error(input), "expected string literal");
+
+        let input = parse_quote! {
+            struct User {
+                #[table(rename)]
+                name: String,
+            }
+        };
+        assert_eq!(error(input), "expected `=`");
+    }
+
+    #[test]
+    fn accepts_configured_fields() {
+        let input = parse_quote! {
+            struct User {
+                #[table(header = "E-mail", rename = "mail", sortable)]
+                email: String,
+                #[table(skip)]
+                password: String,
+            }
+        };
+        let tokens = expand(input).unwrap().to_string();
+        assert!(tokens.contains("\"mail\""));
+        assert!(tokens.contains("\"E-mail\""));
+        assert!(!tokens.contains("password"));
+    }
+
+    #[test]
+    fn headers_are_title_cased() {
+        assert_eq!(title_case("created_at"), "Created At");
+        assert_eq!(title_case("_id__number_"), "Id Number");
+        assert_eq!(title_case("name"), "Name");
+    }
+}
@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 use maplit::hashmap;
+use std::collections::HashMap;
 use table_rs::dioxus::table::Table;
 use table_rs::dioxus::types::{Column, TableClasses, TableTexts};
 
@@ -149,14 +150,14 @@ fn Example3() -> Element {{
                     pre {
                         class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
                         r##"use dioxus::prelude::*;
-use maplit::hashmap;
+use std::collections::HashMap;
 use table_rs::dioxus::table::Table;
 use table_rs::dioxus::types::{{Column, TableClasses, TableTexts}};
 
 
 #[component]
 fn Example4() -> Element {{
-    let data = vec![];
+    let data: Vec<HashMap<&'static str, String>> = vec![];
 
     let columns = vec![
         Column {{ id: "name", header: "Name", sortable: true, ..Default::default() }},
@@ -554,7 +555,7 @@ fn Example3() -> Element {
 
 #[component]
 fn Example4() -> Element {
-    let data = vec![];
+    let data: Vec<HashMap<&'static str, String>> = vec![];
 
     let columns = vec![
         Column {
@@ -0,0 +1,20 @@
+//! Framework-agnostic table engine shared by the Yew, Dioxus and Leptos components.
+//!
+//! This module is always compiled, independently of any UI feature. It holds the data pipeline
+//! (search and column filtering, sorting and pagination) as pure functions over the row data, so every
+//! framework component is a thin renderer over the same behavior.
+
+pub mod compare;
+pub mod data;
+pub mod filter;
+pub mod fuzzy;
+pub mod model;
+pub mod normalize;
+pub mod pagination;
+pub mod query;
+pub mod row;
+pub mod sort_index;
+pub mod source;
+pub mod state;
+pub mod value;
+pub mod virtualize;
@@ -0,0 +1,205 @@
+//! Orderings used when sorting columns: string collations and custom per-column orderings.
+//!
+//! [`Comparator`] and [`SortKeyFn`] wrap a shared closure so that they can live in the
+//! `Clone + PartialEq` column definitions of every framework; two wrappers are equal only when
+//! they share the same closure.
+
+use crate::core::value::CellValue;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// How text values are compared when sorting.
+///
+/// Collations are implemented in pure Rust and do not depend on the browser, so server-side
+/// rendering sorts exactly like the client. Values that compare equal under a collation are
+/// ordered byte-wise, which keeps sorting deterministic.
+///
+/// # Examples
+/// ```rust
+/// use std::cmp::Ordering;
+/// use table_rs::core::compare::Collation;
+///
+/// assert_eq!(Collation::Binary.compare("file10", "file2"), Ordering::Less);
+/// assert_eq!(Collation::Natural.compare("file10", "file2"), Ordering::Greater);
+/// assert_eq!(Collation::CaseInsensitive.compare("apple", "Banana"), Ordering::Less);
+/// assert_eq!(Collation::Unicode.compare("Émile", "Zoe"), Ordering::Less);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Collation {
+    /// Byte-wise comparison of the UTF-8 text (default).
+    #[default]
+    Binary,
+    /// Compares the lowercase text, so `"apple"` sorts before `"Banana"`.
+    CaseInsensitive,
+    /// Case-insensitive, comparing runs of digits by their numeric value, so `"file2"` sorts
+    /// before `"file10"`.
+    Natural,
+    /// Locale-independent approximation of Unicode collation: letters are compared without
+    /// their accents and case first, so `"Émile"` sorts with `"Emile"`; unaccented and lowercase
+    /// letters come first among otherwise equal values.
+    Unicode,
+}
+
+impl Collation {
+    /// Compares `a` with `b` under this collation.
+    pub fn compare(self, a: &str, b: &str) -> Ordering {
+        match self {
+            Collation::Binary => a.cmp(b),
+            Collation::CaseInsensitive => lowercase(a).cmp(lowercase(b)).then_with(|| a.cmp(b)),
+            Collation::Natural => natural(a, b).then_with(|| a.cmp(b)),
+            Collation::Unicode => base_letters(a)
+                .cmp(base_letters(b))
+                .then_with(|| lowercase(a).cmp(lowercase(b)))
+                .then_with(|| a.cmp(b)),
+        }
+    }
+}
+
+fn lowercase(text: &str) -> impl Iterator<Item = char> + '_ {
+    text.chars().flat_map(char::to_lowercase)
+}
+
+/// Lowercase letters of `text` with their diacritics removed.
+fn base_letters(text: &str) -> impl Iterator<Item = char> + '_ {
+    text.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+}
+
+/// Case-insensitive comparison treating runs of ASCII digits as numbers.
+fn natural(mut a: &str, mut b: &str) -> Ordering {
+    loop {
+        let (x, y) = match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => (x, y),
+        };
+
+        if x.is_ascii_digit() && y.is_ascii_digit() {
+            let end_a = a.find(|c: char| !c.is_ascii_digit()).unwrap_or(a.len());
+            let end_b = b.find(|c: char| !c.is_ascii_digit()).unwrap_or(b.len());
+            let digits_a = a[..end_a].trim_start_matches('0');
+            let digits_b = b[..end_b].trim_start_matches('0');
+            let ordering = digits_a
+                .len()
+                .cmp(&digits_b.len())
+                .then_with(|| digits_a.cmp(digits_b));
+            if ordering.is_ne() {
+                return ordering;
+            }
+            a = &a[end_a..];
+            b = &b[end_b..];
+        } else {
+            let ordering = x.to_lowercase().cmp(y.to_lowercase());
+            if ordering.is_ne() {
+                return ordering;
+            }
+            a = &a[x.len_utf8()..];
+            b = &b[y.len_utf8()..];
+        }
+    }
+}
+
+type CompareFn = dyn Fn(&CellValue, &CellValue) -> Ordering + Send + Sync;
+
+/// Compares two non-empty cell values of a column in ascending order.
+///
+/// The table applies the sort direction and the column's `NullOrder` around it, so the closure
+/// never sees [`CellValue::Null`].
+///
+/// # Examples
+/// ```rust
+/// use std::cmp::Ordering;
+/// use table_rs::core::compare::Comparator;
+/// use table_rs::core::value::CellValue;
+///
+/// // Semantic versions: compare the dot-separated parts numerically.
+/// let semver = Comparator::new(|a: &CellValue, b: &CellValue| {
+///     let parts = |v: &CellValue| -> Vec<u64> {
+///         v.to_string().split('.').map(|p| p.parse().unwrap_or(0)).collect()
+///     };
+///     parts(a).cmp(&parts(b))
+/// });
+///
+/// assert_eq!(semver.compare(&"1.10.0".into(), &"1.9.3".into()), Ordering::Greater);
+/// ```
+#[derive(Clone)]
+pub struct Comparator(Arc<CompareFn>);
+
+impl Comparator {
+    /// Wraps an ascending comparison function.
+    pub fn new(
+        compare: impl Fn(&CellValue, &CellValue) -> Ordering + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(compare))
+    }
+
+    /// Compares `a` with `b` in ascending order.
+    pub fn compare(&self, a: &CellValue, b: &CellValue) -> Ordering {
+        (self.0)(a, b)
+    }
+}
+
+impl PartialEq for Comparator {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Comparator(..)")
+    }
+}
+
+/// Maps the value of a cell to the value actually used for sorting.
+///
+/// The extracted keys are compared with the built-in ordering of [`CellValue`], or with the
+/// column's [`Comparator`] when it has one. Keys are computed once per row and sort.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::compare::SortKeyFn;
+/// use table_rs::core::value::CellValue;
+///
+/// // Severity levels: rank them instead of sorting alphabetically.
+/// let severity = SortKeyFn::new(|value: &CellValue| {
+///     let rank = ["Low", "Medium", "High", "Critical"]
+///         .iter()
+///         .position(|level| value.to_string() == *level);
+///     rank.map(|rank| rank as i64).into()
+/// });
+///
+/// assert_eq!(severity.key(&"Critical".into()), CellValue::Int(3));
+/// assert_eq!(severity.key(&"Unknown".into()), CellValue::Null);
+/// ```
+#[derive(Clone)]
+pub struct SortKeyFn(Arc<dyn Fn(&CellValue) -> CellValue + Send + Sync>);
+
+impl SortKeyFn {
+    /// Wraps a sort key extraction function.
+    pub fn new(key: impl Fn(&CellValue) -> CellValue + Send + Sync + 'static) -> Self {
+        Self(Arc::new(key))
+    }
+
+    /// Returns the sort key of `value`.
+    pub fn key(&self, value: &CellValue) -> CellValue {
+        (self.0)(value)
+    }
+}
+
+impl PartialEq for SortKeyFn {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for SortKeyFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SortKeyFn(..)")
+    }
+}
@@ -0,0 +1,146 @@
+//! Shared table data, cheap to clone and to compare.
+//!
+//! Framework components receive their rows as props, which are cloned and compared on every
+//! render. [`SharedRows`] keeps the rows behind a reference-counted slice instead: cloning it
+//! copies a pointer, and two values are equal when they share the same slice, without looking at
+//! the rows. New data is a new [`SharedRows`], e.g. built from a `Vec` with `into()`, or from
+//! the previous rows with [`SharedRows::appended`] to let the tables keep their sorted orders.
+
+use std::fmt;
+use std::ops::Deref;
+use std::rc::{Rc, Weak};
+
+/// Rows shared between renders, compared by identity rather than by value.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::data::SharedRows;
+///
+/// let rows: SharedRows<&str> = vec!["Ferris", "Crab"].into();
+/// let same = rows.clone();
+/// assert_eq!(rows, same);
+/// assert_eq!(rows[0], "Ferris");
+///
+/// // Equal rows held in another slice are different data.
+/// let copy: SharedRows<&str> = vec!["Ferris", "Crab"].into();
+/// assert_ne!(rows, copy);
+/// ```
+pub struct SharedRows<T> {
+    rows: Rc<[T]>,
+    /// The rows these were built from by [`SharedRows::appended`].
+    parent: Option<Weak<[T]>>,
+}
+
+impl<T> SharedRows<T> {
+    fn new(rows: Rc<[T]>) -> Self {
+        Self { rows, parent: None }
+    }
+
+    /// Returns the rows as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.rows
+    }
+
+    /// Returns new data made of these rows followed by `rows`.
+    ///
+    /// Tables told about the previous rows recognize the new data as an append, and merge the new
+    /// rows into their sorted orders instead of sorting every row again.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::data::SharedRows;
+    ///
+    /// let rows: SharedRows<u32> = vec![1, 2].into();
+    /// let more = rows.appended([3]);
+    /// assert_eq!(*more, [1, 2, 3]);
+    /// assert_ne!(more, rows);
+    /// ```
+    pub fn appended(&self, rows: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            rows: self.rows.iter().cloned().chain(rows).collect(),
+            parent: Some(Rc::downgrade(&self.rows)),
+        }
+    }
+
+    /// Whether these rows are `previous`, or were built from it by [`SharedRows::appended`].
+    ///
+    /// The check only looks at where the rows come from, never at the rows themselves, so equal
+    /// rows collected anew do not count as an append.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::data::SharedRows;
+    ///
+    /// let rows: SharedRows<u32> = vec![1, 2].into();
+    /// let more = rows.appended([3]);
+    /// assert!(more.extends(&rows));
+    /// assert!(!rows.extends(&more));
+    ///
+    /// let copy: SharedRows<u32> = vec![1, 2, 3].into();
+    /// assert!(!copy.extends(&rows));
+    /// ```
+    pub fn extends(&self, previous: &Self) -> bool {
+        self == previous
+            || self.parent.as_ref().is_some_and(|parent| {
+                std::ptr::addr_eq(parent.as_ptr(), Rc::as_ptr(&previous.rows))
+            })
+    }
+}
+
+impl<T> Clone for SharedRows<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            parent: self.parent.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for SharedRows<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.rows, &other.rows)
+    }
+}
+
+impl<T> Eq for SharedRows<T> {}
+
+impl<T> Default for SharedRows<T> {
+    fn default() -> Self {
+        Self::new(Rc::new([]))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedRows<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rows.iter()).finish()
+    }
+}
+
+impl<T> Deref for SharedRows<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.rows
+    }
+}
+
+impl<T> From<Vec<T>> for SharedRows<T> {
+    fn from(rows: Vec<T>) -> Self {
+        Self::new(rows.into())
+    }
+}
+
+impl<T> From<Rc<[T]>> for SharedRows<T> {
+    fn from(rows: Rc<[T]>) -> Self {
+        Self::new(rows)
+    }
+}
+
+impl<T> FromIterator<T> for SharedRows<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(rows: I) -> Self {
+        Self::new(rows.into_iter().collect())
+    }
+}
@@ -0,0 +1,362 @@
+//! Column-level filters, applied on top of the global search.
+//!
+//! A [`ColumnFilter`] holds the value of one column's filter, while [`FilterKind`] describes the
+//! widget used to edit it. Widgets report raw user input as a [`FilterInput`], which
+//! [`FilterKind::apply`] turns into the next filter value, so every framework renders the same
+//! filter row on top of the same logic.
+//!
+//! Categorical columns can instead use a [`FilterKind::Facet`] menu, listing each distinct value of
+//! the column with its row count as computed by
+//! [`TableModel::facets`](crate::core::model::TableModel::facets).
+
+use crate::core::value::{CellValue, Date, DateTime};
+use std::collections::BTreeMap;
+
+/// Active column filters, keyed by column id.
+pub type ColumnFilters = BTreeMap<&'static str, ColumnFilter>;
+
+/// Facets of the columns using a [`FilterKind::Facet`] menu, keyed by column id.
+pub type ColumnFacets = BTreeMap<&'static str, Vec<Facet>>;
+
+/// A distinct value of a column, along with the number of rows holding it.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Facet {
+    /// Text of the value; empty for empty cells.
+    pub value: String,
+    /// Number of rows holding the value.
+    pub count: usize,
+}
+
+/// How a text filter matches cell values; matching ignores case.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TextMatch {
+    /// The cell contains the filter text (default).
+    #[default]
+    Contains,
+    /// The cell is exactly the filter text.
+    Equals,
+    /// The cell starts with the filter text.
+    StartsWith,
+}
+
+/// The filter applied to the values of one column.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::filter::{ColumnFilter, TextMatch};
+/// use table_rs::core::value::{CellValue, Date};
+///
+/// let name = ColumnFilter::Text { op: TextMatch::StartsWith, value: "fer".into() };
+/// assert!(name.matches(&CellValue::from("Ferris")));
+/// assert!(!name.matches(&CellValue::from("Crab")));
+///
+/// let age = ColumnFilter::Range { min: Some(18.0), max: None };
+/// assert!(age.matches(&CellValue::from("42")));
+/// assert!(!age.matches(&CellValue::Int(12)));
+///
+/// let joined = ColumnFilter::DateRange { from: Date::parse("2024-01-01"), to: None };
+/// assert!(joined.matches(&CellValue::from("2024-06-30T12:00:00Z")));
+///
+/// let status = ColumnFilter::NoneOf(vec!["archived".into()]);
+/// assert!(status.matches(&CellValue::from("active")));
+/// assert!(status.matches(&CellValue::Null));
+/// assert!(!status.matches(&CellValue::from("archived")));
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum ColumnFilter {
+    /// Keeps cells whose text matches `value` according to `op`.
+    Text {
+        /// How the text is matched.
+        op: TextMatch,
+        /// Text to look for.
+        value: String,
+    },
+    /// Keeps numeric cells within the inclusive bounds.
+    Range {
+        /// Smallest accepted value, if bounded.
+        min: Option<f64>,
+        /// Largest accepted value, if bounded.
+        max: Option<f64>,
+    },
+    /// Keeps date and date-time cells whose date falls within the inclusive bounds.
+    DateRange {
+        /// Earliest accepted date, if bounded.
+        from: Option<Date>,
+        /// Latest accepted date, if bounded.
+        to: Option<Date>,
+    },
+    /// Keeps cells whose text is one of the selected options.
+    OneOf(Vec<String>),
+    /// Keeps cells whose text is none of the excluded values; empty cells are excluded with `""`.
+    NoneOf(Vec<String>),
+}
+
+impl ColumnFilter {
+    /// Returns `true` if the filter restricts anything, i.e. it has text, a bound or a selection.
+    pub fn is_active(&self) -> bool {
+        match self {
+            ColumnFilter::Text { value, .. } => !value.is_empty(),
+            ColumnFilter::Range { min, max } => min.is_some() || max.is_some(),
+            ColumnFilter::DateRange { from, to } => from.is_some() || to.is_some(),
+            ColumnFilter::OneOf(options) => !options.is_empty(),
+            ColumnFilter::NoneOf(excluded) => !excluded.is_empty(),
+        }
+    }
+
+    /// Returns `true` if `value` passes the filter. Empty cells only pass inactive filters, and
+    /// exclusions that do not list the empty value.
+    pub fn matches(&self, value: &CellValue) -> bool {
+        self.matcher().matches(value)
+    }
+
+    /// Prepares the filter to test many cells, lowercasing its text once rather than per cell.
+    pub(crate) fn matcher(&self) -> FilterMatcher<'_> {
+        let needle = match self {
+            ColumnFilter::Text { value, .. } => value.to_lowercase(),
+            _ => String::new(),
+        };
+        FilterMatcher {
+            filter: self,
+            needle,
+        }
+    }
+
+    /// Text shown in the text input of the filter widget.
+    pub fn text(&self) -> &str {
+        match self {
+            ColumnFilter::Text { value, .. } => value,
+            _ => "",
+        }
+    }
+
+    /// Lower bound shown in the "min" or "from" input of the filter widget.
+    pub fn min_text(&self) -> String {
+        match self {
+            ColumnFilter::Range { min, .. } => min.map(|min| min.to_string()).unwrap_or_default(),
+            ColumnFilter::DateRange { from, .. } => {
+                from.map(|from| from.to_string()).unwrap_or_default()
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Upper bound shown in the "max" or "to" input of the filter widget.
+    pub fn max_text(&self) -> String {
+        match self {
+            ColumnFilter::Range { max, .. } => max.map(|max| max.to_string()).unwrap_or_default(),
+            ColumnFilter::DateRange { to, .. } => to.map(|to| to.to_string()).unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    /// Returns `true` if `option` is selected in a multi-select filter.
+    pub fn is_selected(&self, option: &str) -> bool {
+        matches!(self, ColumnFilter::OneOf(options) if options.iter().any(|o| o == option))
+    }
+
+    /// Returns `true` if `value` is excluded by a facet filter.
+    pub fn is_excluded(&self, value: &str) -> bool {
+        matches!(self, ColumnFilter::NoneOf(excluded) if excluded.iter().any(|e| e == value))
+    }
+}
+
+/// A [`ColumnFilter`] prepared by [`ColumnFilter::matcher`] to test many cells.
+pub(crate) struct FilterMatcher<'a> {
+    filter: &'a ColumnFilter,
+    /// Lowercased text of a text filter.
+    needle: String,
+}
+
+impl FilterMatcher<'_> {
+    /// Returns `true` if `value` passes the filter; see [`ColumnFilter::matches`].
+    pub(crate) fn matches(&self, value: &CellValue) -> bool {
+        if !self.filter.is_active() {
+            return true;
+        }
+        if value.is_null() && !matches!(self.filter, ColumnFilter::NoneOf(_)) {
+            return false;
+        }
+
+        match self.filter {
+            ColumnFilter::Text { op, .. } => {
+                let text = value.to_string().to_lowercase();
+                match op {
+                    TextMatch::Contains => text.contains(&self.needle),
+                    TextMatch::Equals => text == self.needle,
+                    TextMatch::StartsWith => text.starts_with(&self.needle),
+                }
+            }
+            ColumnFilter::Range { min, max } => as_number(value).is_some_and(|number| {
+                min.is_none_or(|min| number >= min) && max.is_none_or(|max| number <= max)
+            }),
+            ColumnFilter::DateRange { from, to } => as_date(value).is_some_and(|date| {
+                from.is_none_or(|from| date >= from) && to.is_none_or(|to| date <= to)
+            }),
+            ColumnFilter::OneOf(options) => {
+                let text = value.to_string();
+                options.contains(&text)
+            }
+            ColumnFilter::NoneOf(excluded) => {
+                let text = value.to_string();
+                !excluded.contains(&text)
+            }
+        }
+    }
+}
+
+fn as_number(value: &CellValue) -> Option<f64> {
+    match value {
+        CellValue::Int(int) => Some(*int as f64),
+        CellValue::Float(float) => Some(*float),
+        CellValue::Text(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn as_date(value: &CellValue) -> Option<Date> {
+    match value {
+        CellValue::Date(date) => Some(*date),
+        CellValue::DateTime(date_time) => Some(date_time.date),
+        CellValue::Text(text) => DateTime::parse(text).map(|date_time| date_time.date),
+        _ => None,
+    }
+}
+
+/// The widget rendered in a column's cell of the filter row.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum FilterKind {
+    /// No filter widget for the column (default).
+    #[default]
+    None,
+    /// A text input matching cells as given.
+    Text(TextMatch),
+    /// Two number inputs bounding the cell values.
+    Number,
+    /// Two date inputs bounding the cell dates.
+    Date,
+    /// A list of checkboxes, keeping cells equal to any checked option.
+    Select(Vec<&'static str>),
+    /// A dropdown menu in the column header, listing the distinct values of the column with their
+    /// row counts; unchecking a value hides its rows.
+    Facet,
+}
+
+/// Raw input reported by a filter widget.
+#[derive(Clone, PartialEq, Debug)]
+pub enum FilterInput {
+    /// New content of the text input.
+    Text(String),
+    /// New content of the "min" (or "from") input.
+    Min(String),
+    /// New content of the "max" (or "to") input.
+    Max(String),
+    /// An option of a multi-select or a facet value was checked or unchecked.
+    Toggle(String),
+}
+
+impl FilterKind {
+    /// Returns `true` if the column gets a widget in the filter row. Facet menus are rendered in
+    /// the column header instead.
+    pub fn in_filter_row(&self) -> bool {
+        !matches!(self, FilterKind::None | FilterKind::Facet)
+    }
+
+    /// Applies `input` to the `current` filter of a column, returning the new filter or `None`
+    /// when it no longer restricts anything.
+    ///
+    /// Unparseable bounds are treated as empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::filter::{ColumnFilter, FilterInput, FilterKind};
+    ///
+    /// let kind = FilterKind::Number;
+    /// let filter = kind.apply(None, FilterInput::Min("10".into()));
+    /// assert_eq!(filter, Some(ColumnFilter::Range { min: Some(10.0), max: None }));
+    ///
+    /// let filter = kind.apply(filter.as_ref(), FilterInput::Max("20".into()));
+    /// assert_eq!(filter, Some(ColumnFilter::Range { min: Some(10.0), max: Some(20.0) }));
+    ///
+    /// let filter = kind.apply(filter.as_ref(), FilterInput::Min(String::new()));
+    /// let filter = kind.apply(filter.as_ref(), FilterInput::Max(String::new()));
+    /// assert_eq!(filter, None);
+    ///
+    /// let filter = FilterKind::Facet.apply(None, FilterInput::Toggle("archived".into()));
+    /// assert_eq!(filter, Some(ColumnFilter::NoneOf(vec!["archived".into()])));
+    /// ```
+    pub fn apply(
+        &self,
+        current: Option<&ColumnFilter>,
+        input: FilterInput,
+    ) -> Option<ColumnFilter> {
+        let filter = match (self, input) {
+            (FilterKind::Text(op), FilterInput::Text(value)) => {
+                ColumnFilter::Text { op: *op, value }
+            }
+            (FilterKind::Number, input) => {
+                let (mut min, mut max) = match current {
+                    Some(ColumnFilter::Range { min, max }) => (*min, *max),
+                    _ => (None, None),
+                };
+                match input {
+                    FilterInput::Min(text) => min = text.trim().parse().ok(),
+                    FilterInput::Max(text) => max = text.trim().parse().ok(),
+                    _ => {}
+                }
+                ColumnFilter::Range { min, max }
+            }
+            (FilterKind::Date, input) => {
+                let (mut from, mut to) = match current {
+                    Some(ColumnFilter::DateRange { from, to }) => (*from, *to),
+                    _ => (None, None),
+                };
+                match input {
+                    FilterInput::Min(text) => from = Date::parse(&text),
+                    FilterInput::Max(text) => to = Date::parse(&text),
+                    _ => {}
+                }
+                ColumnFilter::DateRange { from, to }
+            }
+            (FilterKind::Select(_), FilterInput::Toggle(option)) => {
+                let mut options = match current {
+                    Some(ColumnFilter::OneOf(options)) => options.clone(),
+                    _ => Vec::new(),
+                };
+                match options.iter().position(|o| *o == option) {
+                    Some(index) => {
+                        options.remove(index);
+                    }
+                    None => options.push(option),
+                }
+                ColumnFilter::OneOf(options)
+            }
+            (FilterKind::Facet, FilterInput::Toggle(value)) => {
+                let mut excluded = match current {
+                    Some(ColumnFilter::NoneOf(excluded)) => excluded.clone(),
+                    _ => Vec::new(),
+                };
+                match excluded.iter().position(|e| *e == value) {
+                    Some(index) => {
+                        excluded.remove(index);
+                    }
+                    None => excluded.push(value),
+                }
+                ColumnFilter::NoneOf(excluded)
+            }
+            _ => return current.cloned(),
+        };
+        filter.is_active().then_some(filter)
+    }
+}
+
+/// Sets or clears the filter of `column`, as returned by [`FilterKind::apply`].
+pub fn set_filter(filters: &mut ColumnFilters, column: &'static str, filter: Option<ColumnFilter>) {
+    match filter {
+        Some(filter) => {
+            filters.insert(column, filter);
+        }
+        None => {
+            filters.remove(column);
+        }
+    }
+}
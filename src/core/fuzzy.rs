@@ -0,0 +1,290 @@
+//! Typo-tolerant search, used by [`SearchMode::Fuzzy`](crate::core::state::SearchMode::Fuzzy).
+//!
+//! Each whitespace-separated word of the query is matched against the cell text, ignoring case,
+//! and the best of three strategies wins:
+//!
+//! 1. the word appears as is, e.g. `rris` in `Ferris`;
+//! 2. its characters appear in order, e.g. `frs` in `Ferris`;
+//! 3. it appears with a few typos (missing, extra, wrong or swapped characters), e.g. `ferirs` in
+//!    `Ferris`; one typo is allowed per four characters of the word.
+//!
+//! Matches carry a score, higher for the earlier strategies, and the byte ranges of the matched
+//! characters, which the table bodies highlight.
+
+use std::ops::Range;
+
+/// A successful fuzzy match of a pattern in some text.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FuzzyMatch {
+    /// How well the pattern matched; higher is better.
+    pub score: u32,
+    /// Byte ranges of the matched characters in the text, in order and non-overlapping.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Matches `pattern` against `text`, ignoring case.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::fuzzy::fuzzy_match;
+///
+/// let exact = fuzzy_match("rris", "Ferris").unwrap();
+/// assert_eq!(exact.ranges, [2..6]);
+///
+/// let scattered = fuzzy_match("frs", "Ferris").unwrap();
+/// assert_eq!(scattered.ranges, [0..1, 2..3, 5..6]);
+///
+/// let typo = fuzzy_match("ferirs", "Ferris").unwrap();
+/// assert_eq!(typo.ranges, [0..6]);
+///
+/// assert!(exact.score > scattered.score && scattered.score > typo.score);
+/// assert_eq!(fuzzy_match("crab", "Ferris"), None);
+/// ```
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    FuzzyMatcher::new(pattern).find(text)
+}
+
+/// A pattern matched against many texts, e.g. a search word against every cell of a table,
+/// reusing its buffers from one text to the next.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::fuzzy::{FuzzyMatcher, fuzzy_match};
+///
+/// let mut matcher = FuzzyMatcher::new("ferirs");
+/// for cell in ["Ferris", "Crab", "ferris the crab"] {
+///     assert_eq!(matcher.find(cell), fuzzy_match("ferirs", cell));
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FuzzyMatcher {
+    pattern: Vec<char>,
+    text: Vec<(usize, char)>,
+    distance: Vec<usize>,
+    start: Vec<usize>,
+}
+
+impl FuzzyMatcher {
+    /// Prepares `pattern` for matching, ignoring case.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.chars().map(lower).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Matches the pattern against `text`; see [`fuzzy_match`].
+    pub fn find(&mut self, text: &str) -> Option<FuzzyMatch> {
+        let text_len = text.len();
+        self.text.clear();
+        self.text
+            .extend(text.char_indices().map(|(i, c)| (i, lower(c))));
+        let (pattern, text) = (&self.pattern, &self.text);
+        let offset = |index: usize| text.get(index).map_or(text_len, |(i, _)| *i);
+        let span = |start: usize, end: usize| offset(start)..offset(end);
+
+        if pattern.is_empty() {
+            return Some(FuzzyMatch {
+                score: 0,
+                ranges: Vec::new(),
+            });
+        }
+        let m = pattern.len();
+
+        if let Some(start) = (0..text.len().saturating_sub(m - 1))
+            .find(|&start| text[start..start + m].iter().map(|(_, c)| c).eq(pattern))
+        {
+            return Some(FuzzyMatch {
+                score: 3000 - start.min(999) as u32,
+                ranges: vec![span(start, start + m)],
+            });
+        }
+
+        if let Some(indices) = subsequence(pattern, text) {
+            let gaps = indices[m - 1] + 1 - indices[0] - m;
+            let mut ranges: Vec<Range<usize>> = Vec::new();
+            for index in indices {
+                match ranges.last_mut() {
+                    Some(last) if last.end == offset(index) => last.end = offset(index + 1),
+                    _ => ranges.push(span(index, index + 1)),
+                }
+            }
+            return Some(FuzzyMatch {
+                score: 2000 - gaps.min(999) as u32,
+                ranges,
+            });
+        }
+
+        let (distance, start, end) =
+            approximate(pattern, text, &mut self.distance, &mut self.start)?;
+        (distance <= m / 4).then(|| FuzzyMatch {
+            score: 1000 - 100 * distance.min(10) as u32,
+            ranges: vec![span(start, end)],
+        })
+    }
+}
+
+fn lower(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Finds the shortest run of `text` holding the characters of `pattern` in order, returning the
+/// indices of the matched characters.
+fn subsequence(pattern: &[char], text: &[(usize, char)]) -> Option<Vec<usize>> {
+    let mut best: Option<Vec<usize>> = None;
+    for start in (0..text.len()).filter(|&start| text[start].1 == pattern[0]) {
+        let mut indices = vec![start];
+        let mut next = start + 1;
+        for &c in &pattern[1..] {
+            let Some(found) = (next..text.len()).find(|&i| text[i].1 == c) else {
+                break;
+            };
+            indices.push(found);
+            next = found + 1;
+        }
+        if indices.len() < pattern.len() {
+            // Later starts cannot finish the pattern either.
+            break;
+        }
+        let len = |indices: &[usize]| indices[indices.len() - 1] - indices[0];
+        if best.as_deref().is_none_or(|best| len(&indices) < len(best)) {
+            best = Some(indices);
+        }
+    }
+    best
+}
+
+/// Finds the run of `text` closest to `pattern` in edit distance, counting swapped adjacent
+/// characters as one edit. Returns the distance and the run as a range of character indices.
+///
+/// `distance` and `start` are scratch buffers, reused across calls.
+fn approximate(
+    pattern: &[char],
+    text: &[(usize, char)],
+    distance: &mut Vec<usize>,
+    start: &mut Vec<usize>,
+) -> Option<(usize, usize, usize)> {
+    let (m, n) = (pattern.len(), text.len());
+    let at = |i: usize, j: usize| i * (n + 1) + j;
+    distance.clear();
+    distance.resize((m + 1) * (n + 1), 0);
+    start.clear();
+    start.resize((m + 1) * (n + 1), 0);
+    for j in 0..=n {
+        start[at(0, j)] = j;
+    }
+    for i in 1..=m {
+        distance[at(i, 0)] = i;
+        for j in 1..=n {
+            let cost = usize::from(pattern[i - 1] != text[j - 1].1);
+            let mut best = (distance[at(i - 1, j - 1)] + cost, start[at(i - 1, j - 1)]);
+            for candidate in [
+                (distance[at(i - 1, j)] + 1, start[at(i - 1, j)]),
+                (distance[at(i, j - 1)] + 1, start[at(i, j - 1)]),
+            ] {
+                if candidate.0 < best.0 {
+                    best = candidate;
+                }
+            }
+            if i > 1
+                && j > 1
+                && pattern[i - 1] == text[j - 2].1
+                && pattern[i - 2] == text[j - 1].1
+                && distance[at(i - 2, j - 2)] + 1 < best.0
+            {
+                best = (distance[at(i - 2, j - 2)] + 1, start[at(i - 2, j - 2)]);
+            }
+            (distance[at(i, j)], start[at(i, j)]) = best;
+        }
+    }
+    (1..=n)
+        .min_by_key(|&j| distance[at(m, j)])
+        .map(|j| (distance[at(m, j)], start[at(m, j)], j))
+}
+
+/// Byte ranges of `text` matched by any word of `query`, sorted and merged.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::fuzzy::highlight_ranges;
+///
+/// assert_eq!(highlight_ranges("fer sa", "Ferris Sanders"), [0..3, 7..9]);
+/// assert!(highlight_ranges("crab", "Ferris").is_empty());
+/// ```
+pub fn highlight_ranges(query: &str, text: &str) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = query
+        .split_whitespace()
+        .filter_map(|word| FuzzyMatcher::new(word).find(text))
+        .flat_map(|m| m.ranges)
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Splits `text` into consecutive segments, flagging those covered by `ranges`.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::fuzzy::split_highlights;
+///
+/// assert_eq!(
+///     split_highlights("Ferris", &[2..4]),
+///     [("Fe", false), ("rr", true), ("is", false)],
+/// );
+/// ```
+pub fn split_highlights<'t>(text: &'t str, ranges: &[Range<usize>]) -> Vec<(&'t str, bool)> {
+    let mut segments = Vec::new();
+    let mut offset = 0;
+    for range in ranges {
+        if range.start > offset {
+            segments.push((&text[offset..range.start], false));
+        }
+        segments.push((&text[range.clone()], true));
+        offset = range.end;
+    }
+    if offset < text.len() {
+        segments.push((&text[offset..], false));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn many_typos_do_not_underflow_the_score() {
+        let pattern = "a".repeat(44);
+        let text = format!("{}{}", "a".repeat(33), "b".repeat(11));
+        let typos = fuzzy_match(&pattern, &text).unwrap();
+        assert_eq!(typos.score, 0);
+    }
+
+    #[test]
+    fn scores_rank_strategies() {
+        let exact = fuzzy_match("ferris", "Ferris").unwrap();
+        let late = fuzzy_match("ferris", &format!("{}Ferris", " ".repeat(5000))).unwrap();
+        let scattered = fuzzy_match("frs", "Ferris").unwrap();
+        let typo = fuzzy_match("ferirs", "Ferris").unwrap();
+        assert_eq!(exact.score, 3000);
+        assert!(late.score > scattered.score);
+        assert!(scattered.score > typo.score);
+        assert!(typo.score < 1000);
+    }
+
+    #[test]
+    fn repeated_first_characters_keep_earlier_matches() {
+        let scattered = fuzzy_match("frs", "Ferris f").unwrap();
+        assert_eq!(scattered.ranges, [0..1, 2..3, 5..6]);
+        let scattered = fuzzy_match("ac", "abc a").unwrap();
+        assert_eq!(scattered.ranges, [0..1, 2..3]);
+    }
+}
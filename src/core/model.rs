@@ -0,0 +1,1300 @@
+use crate::core::compare::{Collation, Comparator, SortKeyFn};
+use crate::core::filter::{ColumnFilters, Facet};
+use crate::core::fuzzy::FuzzyMatcher;
+use crate::core::normalize::Normalizer;
+use crate::core::pagination::clamp_page;
+use crate::core::query::{Query, QueryError};
+use crate::core::row::TableRow;
+use crate::core::sort_index::{ColumnSort, SortCache, SortIndex};
+use crate::core::state::TableState;
+use crate::core::state::{SearchMode, SortKey, SortOrder};
+use crate::core::value::{CellKind, CellValue, NullOrder};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// The default table row: cell text keyed by column id.
+pub type Row = HashMap<&'static str, String>;
+
+/// Describes a column as far as the data pipeline is concerned.
+///
+/// Each framework's `Column` type implements this trait, and plain `&'static str` ids do too,
+/// which is handy when driving [`TableModel`] outside of any UI.
+pub trait ColumnSpec {
+    /// Unique identifier of the column, matching the keys of the row data.
+    fn id(&self) -> &'static str;
+
+    /// How the values of the column are interpreted when sorting.
+    fn kind(&self) -> CellKind {
+        CellKind::Auto
+    }
+
+    /// Where empty values of the column go when sorting.
+    fn nulls(&self) -> NullOrder {
+        NullOrder::Last
+    }
+
+    /// Function mapping each value of the column to the key it is sorted by, if any.
+    fn sort_key(&self) -> Option<&SortKeyFn> {
+        None
+    }
+
+    /// Ordering used instead of the built-in one when sorting the column, if any.
+    fn comparator(&self) -> Option<&Comparator> {
+        None
+    }
+
+    /// Collation used to compare text values of the column, overriding the table's one.
+    fn collation(&self) -> Option<Collation> {
+        None
+    }
+
+    /// Whether the global search looks at the column; scoped query terms always do.
+    fn searchable(&self) -> bool {
+        true
+    }
+
+    /// How the plain search normalizes the cells of the column and the query.
+    fn normalizer(&self) -> Normalizer {
+        Normalizer::default()
+    }
+}
+
+impl ColumnSpec for &'static str {
+    fn id(&self) -> &'static str {
+        self
+    }
+}
+
+/// Columns compared by what the data pipeline reads of them, rather than by every field.
+///
+/// Framework tables key the memoized pipeline on it, so that columns built anew on every render
+/// only run the pipeline again when their id, kind, empty value placement, collation, sort key,
+/// comparator, searchability or normalizer change; headers, styles and render callbacks do not
+/// count. Sort keys and comparators compare by identity: create them once, not on every render.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::model::PipelineColumns;
+///
+/// let columns = PipelineColumns::new(vec!["name", "age"]);
+/// assert_eq!(columns, PipelineColumns::new(vec!["name", "age"]));
+/// assert_ne!(columns, PipelineColumns::new(vec!["name"]));
+/// assert_eq!(columns.columns(), ["name", "age"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PipelineColumns<C> {
+    columns: Vec<C>,
+    keys: Vec<ColumnKey>,
+}
+
+/// What the data pipeline reads of a column.
+#[derive(Clone, PartialEq, Debug)]
+struct ColumnKey {
+    id: &'static str,
+    kind: CellKind,
+    nulls: NullOrder,
+    sort_key: Option<SortKeyFn>,
+    comparator: Option<Comparator>,
+    collation: Option<Collation>,
+    searchable: bool,
+    normalizer: Normalizer,
+}
+
+impl<C: ColumnSpec> PipelineColumns<C> {
+    /// Wraps `columns`, recording what the pipeline reads of them.
+    pub fn new(columns: Vec<C>) -> Self {
+        let keys = columns
+            .iter()
+            .map(|spec| ColumnKey {
+                id: spec.id(),
+                kind: spec.kind(),
+                nulls: spec.nulls(),
+                sort_key: spec.sort_key().cloned(),
+                comparator: spec.comparator().cloned(),
+                collation: spec.collation(),
+                searchable: spec.searchable(),
+                normalizer: spec.normalizer(),
+            })
+            .collect();
+        Self { columns, keys }
+    }
+}
+
+impl<C> PipelineColumns<C> {
+    /// The wrapped columns.
+    pub fn columns(&self) -> &[C] {
+        &self.columns
+    }
+}
+
+impl<C> PartialEq for PipelineColumns<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys
+    }
+}
+
+/// A sort key resolved against the column it refers to.
+struct ResolvedKey<'c> {
+    column: &'static str,
+    order: SortOrder,
+    kind: CellKind,
+    nulls: NullOrder,
+    sort_key: Option<&'c SortKeyFn>,
+    comparator: Option<&'c Comparator>,
+    collation: Collation,
+}
+
+impl ResolvedKey<'_> {
+    fn extract<R: TableRow>(&self, row: &R) -> CellValue {
+        let value = self.kind.coerce(row.value(self.column));
+        match self.sort_key {
+            Some(sort_key) if !value.is_null() => sort_key.key(&value),
+            _ => value,
+        }
+    }
+
+    fn compare(&self, a: &CellValue, b: &CellValue) -> Ordering {
+        match self.comparator {
+            Some(comparator) => compare_with(a, b, self.order, self.nulls, |a, b| {
+                comparator.compare(a, b)
+            }),
+            None if self.collation == Collation::Binary => {
+                compare_values(a, b, self.order, self.nulls)
+            }
+            None => compare_with(a, b, self.order, self.nulls, |a, b| match (a, b) {
+                (CellValue::Text(a), CellValue::Text(b)) => self.collation.compare(a, b),
+                _ => a.cmp(b),
+            }),
+        }
+    }
+}
+
+/// Values of `row` for each key of a sort stack.
+fn extract_keys<R: TableRow>(specs: &[ResolvedKey<'_>], row: &R) -> Vec<CellValue> {
+    specs.iter().map(|key| key.extract(row)).collect()
+}
+
+/// Compares the values of two rows for each key of a sort stack, the first key first.
+fn compare_keys(specs: &[ResolvedKey<'_>], a: &[CellValue], b: &[CellValue]) -> Ordering {
+    specs
+        .iter()
+        .zip(a.iter().zip(b))
+        .map(|(key, (a, b))| key.compare(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// The rows to render for a given [`TableState`], along with page counts.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::model::TableModel;
+/// use table_rs::core::state::TableState;
+///
+/// let rows: Vec<_> = (1..=25).map(|n| maplit::hashmap! { "n" => n.to_string() }).collect();
+/// let columns = ["n"];
+///
+/// // Page 5 no longer exists once only 25 rows are left: the last one is shown instead.
+/// let state = TableState { page: 5, ..Default::default() };
+/// let view = TableModel::new(&rows, &columns).view(&state);
+/// assert_eq!((view.page, view.total_pages, view.rows.len()), (2, 3, 5));
+///
+/// let view = TableModel::new(&rows[..0], &columns).view(&state);
+/// assert_eq!((view.page, view.total_pages, view.rows.len()), (0, 1, 0));
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableView<'a, R = Row> {
+    /// Rows of the current page, filtered and sorted.
+    pub rows: Vec<&'a R>,
+
+    /// Number of rows left after filtering, across all pages.
+    pub total_rows: usize,
+
+    /// Number of pages needed to show every filtered row; at least 1, even without rows.
+    pub total_pages: usize,
+
+    /// Zero-based page shown, [`TableState::page`] clamped to the last page when the rows no
+    /// longer fill it, e.g. after a search or after `data` shrank.
+    pub page: usize,
+
+    /// Why the search query could not be used, in [`SearchMode::Query`]; the search then keeps
+    /// every row.
+    pub search_error: Option<QueryError>,
+}
+
+/// The rows matching a [`TableState`] across all pages, in display order, as indices into the
+/// rows of a [`TableModel`].
+///
+/// Computing it is the costly part of the pipeline; paging through it is cheap, which lets
+/// components keep it around until the search, filters, sort or data change.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::model::TableModel;
+/// use table_rs::core::state::TableState;
+///
+/// let rows: Vec<_> = (1..=25).map(|n| maplit::hashmap! { "n" => n.to_string() }).collect();
+/// let columns = ["n"];
+/// let state = TableState { query: "2".into(), ..Default::default() };
+///
+/// // "2", "12" and "20" to "25".
+/// let order = TableModel::new(&rows, &columns).order(&state);
+/// assert_eq!(order.indices.len(), 8);
+/// assert_eq!(order.total_pages(3), 3);
+/// assert_eq!(order.page(2, 3), [23, 24]);
+/// assert_eq!(order.page(9, 3), [23, 24]);
+/// assert_eq!(order.page(0, 0).len(), 8);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct RowOrder {
+    /// Indices of the rows left after searching and filtering, sorted.
+    pub indices: Vec<usize>,
+
+    /// Why the search query could not be used, in [`SearchMode::Query`]; the search then keeps
+    /// every row.
+    pub search_error: Option<QueryError>,
+}
+
+impl RowOrder {
+    /// Number of pages needed to show every row `page_size` at a time; at least 1.
+    pub fn total_pages(&self, page_size: usize) -> usize {
+        total_pages(self.indices.len(), page_size)
+    }
+
+    /// Indices of the rows shown on `page`, clamped to the last page. A `page_size` of 0 shows
+    /// every row.
+    pub fn page(&self, page: usize, page_size: usize) -> &[usize] {
+        let total_rows = self.indices.len();
+        if page_size == 0 {
+            return &self.indices;
+        }
+        let page = clamp_page(page, self.total_pages(page_size));
+        let start = page.saturating_mul(page_size).min(total_rows);
+        &self.indices[start..start.saturating_add(page_size).min(total_rows)]
+    }
+}
+
+/// The table data pipeline: search filtering, sorting and pagination over borrowed rows.
+///
+/// Rows can be of any type implementing [`TableRow`]. The model never copies row data; it only
+/// hands out references into the slice it was built from.
+///
+/// # Examples
+/// ```rust
+/// use maplit::hashmap;
+/// use table_rs::core::model::TableModel;
+/// use table_rs::core::state::{SortOrder, TableState};
+///
+/// let rows = vec![
+///     hashmap! { "name" => "Ferris".to_string(), "email" => "ferris@opensass.org".to_string() },
+///     hashmap! { "name" => "Crab".to_string(), "email" => "crab@opensass.org".to_string() },
+///     hashmap! { "name" => "Ferros".to_string(), "email" => "ferros@opensass.org".to_string() },
+/// ];
+/// let columns = ["name", "email"];
+/// let model = TableModel::new(&rows, &columns);
+///
+/// let state = TableState {
+///     query: "FER".into(),
+///     sort: vec![("name", SortOrder::Desc)],
+///     page_size: 1,
+///     ..Default::default()
+/// };
+/// let view = model.view(&state);
+///
+/// assert_eq!(view.total_rows, 2);
+/// assert_eq!(view.total_pages, 2);
+/// assert_eq!(view.rows[0]["name"], "Ferros");
+/// ```
+pub struct TableModel<'a, R, C> {
+    rows: &'a [R],
+    columns: &'a [C],
+    collation: Collation,
+}
+
+impl<'a, R: TableRow, C: ColumnSpec> TableModel<'a, R, C> {
+    /// Creates a model over `rows`, considering only the given `columns`.
+    pub fn new(rows: &'a [R], columns: &'a [C]) -> Self {
+        Self {
+            rows,
+            columns,
+            collation: Collation::Binary,
+        }
+    }
+
+    /// Sets the collation used to compare text values of columns that do not set their own.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::compare::Collation;
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::state::SortOrder;
+    ///
+    /// let data = vec![
+    ///     hashmap! { "file" => "file10".to_string() },
+    ///     hashmap! { "file" => "File2".to_string() },
+    ///     hashmap! { "file" => "file1".to_string() },
+    /// ];
+    /// let columns = ["file"];
+    /// let model = TableModel::new(&data, &columns).with_collation(Collation::Natural);
+    ///
+    /// let mut rows = model.filter("");
+    /// model.sort(&mut rows, "file", SortOrder::Asc);
+    /// let files: Vec<_> = rows.iter().map(|row| row["file"].as_str()).collect();
+    /// assert_eq!(files, ["file1", "File2", "file10"]);
+    /// ```
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
+    }
+
+    /// Returns the rows where any searchable column contains `query`, once both are normalized
+    /// with the column's [`Normalizer`] (ignoring case by default).
+    ///
+    /// An empty query keeps every row.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    ///
+    /// let data = vec![
+    ///     hashmap! { "id" => "42".to_string(), "name" => "Ferris".to_string() },
+    ///     hashmap! { "id" => "7".to_string(), "name" => "Crab 42".to_string() },
+    /// ];
+    /// let columns = ["id", "name"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// assert_eq!(model.filter("42").len(), 2);
+    /// assert_eq!(model.filter("FER").len(), 1);
+    /// ```
+    pub fn filter(&self, query: &str) -> Vec<&'a R> {
+        self.rows_at(self.filter_indices(query))
+    }
+
+    fn filter_indices(&self, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return (0..self.rows.len()).collect();
+        }
+
+        let columns: Vec<_> = self
+            .columns
+            .iter()
+            .filter(|col| col.searchable())
+            .filter_map(|col| {
+                let normalizer = col.normalizer();
+                let needle = normalizer.normalize(query);
+                (!needle.is_empty()).then(|| (col.id(), normalizer, needle))
+            })
+            .collect();
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                columns.iter().any(|(id, normalizer, needle)| {
+                    row.cell(id)
+                        .is_some_and(|v| normalizer.normalize(&v).contains(needle.as_ref()))
+                })
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Rows at `indices`, in order.
+    fn rows_at(&self, indices: Vec<usize>) -> Vec<&'a R> {
+        indices.into_iter().map(|index| &self.rows[index]).collect()
+    }
+
+    /// Parses `input` with [`Query::parse`], also rejecting terms scoped to a field that is not
+    /// the id of a column. Fields are matched ignoring ASCII case.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::query::QueryError;
+    ///
+    /// let data = vec![hashmap! { "name" => "Ferris".to_string() }];
+    /// let columns = ["name"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// assert!(model.parse_query("Name:fer").is_ok());
+    /// assert_eq!(
+    ///     model.parse_query("crab age:>3"),
+    ///     Err(QueryError::UnknownField { field: "age".into(), position: 5 }),
+    /// );
+    /// ```
+    pub fn parse_query(&self, input: &str) -> Result<Query, QueryError> {
+        let query = Query::parse(input)?;
+        for term in query.terms() {
+            if let Some(field) = &term.field
+                && self.column(field).is_none()
+            {
+                return Err(QueryError::UnknownField {
+                    field: field.clone(),
+                    position: term.position,
+                });
+            }
+        }
+        Ok(query)
+    }
+
+    fn column(&self, field: &str) -> Option<&C> {
+        self.columns
+            .iter()
+            .find(|c| c.id().eq_ignore_ascii_case(field))
+    }
+
+    /// Returns the rows matching `query`: every term of any of its alternatives must match.
+    ///
+    /// Scoped terms look at their column, interpreted according to its [`CellKind`]; unscoped
+    /// terms match when any searchable column does. Terms scoped to unknown fields never match.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    ///
+    /// let data = vec![
+    ///     hashmap! { "name" => "Ferris".to_string(), "status" => "active".to_string(), "age" => "42".to_string() },
+    ///     hashmap! { "name" => "test user".to_string(), "status" => "active".to_string(), "age" => "35".to_string() },
+    ///     hashmap! { "name" => "Crab".to_string(), "status" => "banned".to_string(), "age" => "7".to_string() },
+    /// ];
+    /// let columns = ["name", "status", "age"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let query = model.parse_query(r#"status:active age:>30 -name:"test user""#).unwrap();
+    /// let names: Vec<_> = model.search(&query).iter().map(|row| row["name"].as_str()).collect();
+    /// assert_eq!(names, ["Ferris"]);
+    ///
+    /// let query = model.parse_query("age:<10 OR fer").unwrap();
+    /// let names: Vec<_> = model.search(&query).iter().map(|row| row["name"].as_str()).collect();
+    /// assert_eq!(names, ["Ferris", "Crab"]);
+    /// ```
+    pub fn search(&self, query: &Query) -> Vec<&'a R> {
+        self.rows_at(self.search_indices(query))
+    }
+
+    fn search_indices(&self, query: &Query) -> Vec<usize> {
+        if query.is_empty() {
+            return (0..self.rows.len()).collect();
+        }
+
+        let alternatives: Vec<Vec<_>> = query
+            .alternatives()
+            .iter()
+            .map(|terms| {
+                terms
+                    .iter()
+                    .map(|term| {
+                        let columns: Vec<_> = match &term.field {
+                            Some(field) => self.column(field).into_iter().collect(),
+                            None => self.columns.iter().filter(|c| c.searchable()).collect(),
+                        };
+                        (term, columns)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                alternatives.iter().any(|terms| {
+                    terms.iter().all(|(term, columns)| {
+                        let hit = columns
+                            .iter()
+                            .any(|col| term.matches(&col.kind().coerce(row.value(col.id()))));
+                        hit != term.negated
+                    })
+                })
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns the rows where every word of `query` fuzzily matches some searchable column, along
+    /// with their scores: the sum over the words of their best score in the row. See
+    /// [`crate::core::fuzzy`] for how words are matched.
+    ///
+    /// Rows are returned in their original order, and every row (with a score of `0`) when the
+    /// query has no words.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    ///
+    /// let data = vec![
+    ///     hashmap! { "name" => "Crab".to_string() },
+    ///     hashmap! { "name" => "Ferris".to_string() },
+    ///     hashmap! { "name" => "Ferrari".to_string() },
+    /// ];
+    /// let columns = ["name"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let matches = model.fuzzy_search("ferirs");
+    /// assert_eq!(matches.len(), 1);
+    /// assert_eq!(matches[0].0["name"], "Ferris");
+    /// ```
+    pub fn fuzzy_search(&self, query: &str) -> Vec<(&'a R, u32)> {
+        self.fuzzy_indices(query)
+            .into_iter()
+            .map(|(index, score)| (&self.rows[index], score))
+            .collect()
+    }
+
+    fn fuzzy_indices(&self, query: &str) -> Vec<(usize, u32)> {
+        // One matcher per word, reusing its buffers across every cell.
+        let mut words: Vec<FuzzyMatcher> =
+            query.split_whitespace().map(FuzzyMatcher::new).collect();
+        self.rows
+            .iter()
+            .enumerate()
+            .filter_map(|(index, row)| {
+                let cells: Vec<_> = self
+                    .columns
+                    .iter()
+                    .filter(|col| col.searchable())
+                    .filter_map(|col| row.cell(col.id()))
+                    .collect();
+                let score = words
+                    .iter_mut()
+                    .map(|word| {
+                        cells
+                            .iter()
+                            .filter_map(|cell| word.find(cell))
+                            .map(|m| m.score)
+                            .max()
+                    })
+                    .sum::<Option<u32>>()?;
+                Some((index, score))
+            })
+            .collect()
+    }
+
+    /// Applies the search of `state` according to its [`SearchMode`], along with the query
+    /// error, if any.
+    fn search_rows(&self, state: &TableState) -> (Vec<usize>, Option<QueryError>) {
+        match state.search_mode {
+            SearchMode::Plain => (self.filter_indices(&state.query), None),
+            SearchMode::Fuzzy => {
+                let mut matches = self.fuzzy_indices(&state.query);
+                if state.fuzzy_rank && state.sort.is_empty() {
+                    matches.sort_by(|(_, a), (_, b)| b.cmp(a));
+                }
+                (matches.into_iter().map(|(index, _)| index).collect(), None)
+            }
+            SearchMode::Query => match self.parse_query(&state.query) {
+                Ok(query) => (self.search_indices(&query), None),
+                Err(error) => ((0..self.rows.len()).collect(), Some(error)),
+            },
+        }
+    }
+
+    /// Keeps the `rows` passing every column filter, comparing filters with the column values
+    /// interpreted according to their [`CellKind`].
+    ///
+    /// Filters on columns that are not part of the model are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::filter::{ColumnFilter, ColumnFilters};
+    /// use table_rs::core::model::TableModel;
+    ///
+    /// let data = vec![
+    ///     hashmap! { "name" => "Ferris".to_string(), "age" => "12".to_string() },
+    ///     hashmap! { "name" => "Ferros".to_string(), "age" => "31".to_string() },
+    ///     hashmap! { "name" => "Crab".to_string(), "age" => "40".to_string() },
+    /// ];
+    /// let columns = ["name", "age"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let mut filters = ColumnFilters::new();
+    /// filters.insert("age", ColumnFilter::Range { min: Some(18.0), max: None });
+    ///
+    /// let mut rows = model.filter("fer");
+    /// model.filter_columns(&mut rows, &filters);
+    /// assert_eq!(rows.len(), 1);
+    /// assert_eq!(rows[0]["name"], "Ferros");
+    /// ```
+    pub fn filter_columns(&self, rows: &mut Vec<&'a R>, filters: &ColumnFilters) {
+        self.retain_filtered(rows, |row| row, filters);
+    }
+
+    /// Keeps the `items` whose row passes every column filter.
+    fn retain_filtered<I: Copy>(
+        &self,
+        items: &mut Vec<I>,
+        row: impl Fn(I) -> &'a R,
+        filters: &ColumnFilters,
+    ) {
+        let active: Vec<_> = filters
+            .iter()
+            .filter(|(_, filter)| filter.is_active())
+            .filter_map(|(column, filter)| {
+                let spec = self.columns.iter().find(|c| c.id() == *column)?;
+                Some((*column, spec.kind(), filter.matcher()))
+            })
+            .collect();
+        if active.is_empty() {
+            return;
+        }
+
+        items.retain(|&item| {
+            let row = row(item);
+            active
+                .iter()
+                .all(|(column, kind, filter)| filter.matches(&kind.coerce(row.value(column))))
+        });
+    }
+
+    /// Sorts `rows` in place by the values of `column`, interpreted according to its
+    /// [`CellKind`]. Empty values are placed according to the column's [`NullOrder`] regardless
+    /// of `order`.
+    ///
+    /// When the column provides a [`ColumnSpec::sort_key`], rows are sorted by the extracted keys;
+    /// when it provides a [`ColumnSpec::comparator`], that ordering replaces the built-in one.
+    /// Otherwise text values are compared with the column's [`ColumnSpec::collation`], falling
+    /// back to the model's one.
+    ///
+    /// Columns that are not part of the model are ignored and leave `rows` untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::{ColumnSpec, TableModel};
+    /// use table_rs::core::state::SortOrder;
+    /// use table_rs::core::value::CellKind;
+    ///
+    /// struct Age;
+    ///
+    /// impl ColumnSpec for Age {
+    ///     fn id(&self) -> &'static str {
+    ///         "age"
+    ///     }
+    ///
+    ///     fn kind(&self) -> CellKind {
+    ///         CellKind::Int
+    ///     }
+    /// }
+    ///
+    /// let data = vec![
+    ///     hashmap! { "age" => "100".to_string() },
+    ///     hashmap! { "age" => "".to_string() },
+    ///     hashmap! { "age" => "18".to_string() },
+    /// ];
+    /// let columns = [Age];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let mut rows = model.filter("");
+    /// model.sort(&mut rows, "age", SortOrder::Desc);
+    /// let ages: Vec<_> = rows.iter().map(|row| row["age"].as_str()).collect();
+    /// assert_eq!(ages, ["100", "18", ""]);
+    /// ```
+    pub fn sort(&self, rows: &mut [&'a R], column: &'static str, order: SortOrder) {
+        self.sort_by_keys(rows, &[(column, order)]);
+    }
+
+    /// Sorts `rows` in place by a stack of keys: rows are ordered by the first key, ties by the
+    /// second one, and so on. Each key is compared as in [`TableModel::sort`].
+    ///
+    /// Keys naming columns that are not part of the model are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::state::SortOrder;
+    ///
+    /// let data = vec![
+    ///     hashmap! { "department" => "Ops".to_string(), "name" => "Crab".to_string() },
+    ///     hashmap! { "department" => "Dev".to_string(), "name" => "Ferris".to_string() },
+    ///     hashmap! { "department" => "Ops".to_string(), "name" => "Ferros".to_string() },
+    /// ];
+    /// let columns = ["department", "name"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let mut rows = model.filter("");
+    /// model.sort_by_keys(&mut rows, &[("department", SortOrder::Asc), ("name", SortOrder::Desc)]);
+    /// let names: Vec<_> = rows.iter().map(|row| row["name"].as_str()).collect();
+    /// assert_eq!(names, ["Ferris", "Ferros", "Crab"]);
+    /// ```
+    pub fn sort_by_keys(&self, rows: &mut [&'a R], keys: &[SortKey]) {
+        self.sort_items(rows, |row| row, keys);
+    }
+
+    /// Sorts `items` in place by the values of their rows, as [`Self::sort_by_keys`] does.
+    fn sort_items<I: Copy>(&self, items: &mut [I], row: impl Fn(I) -> &'a R, keys: &[SortKey]) {
+        let specs = self.resolve_keys(keys);
+        if specs.is_empty() {
+            return;
+        }
+
+        let mut keyed: Vec<(Vec<CellValue>, I)> = items
+            .iter()
+            .map(|&item| (extract_keys(&specs, row(item)), item))
+            .collect();
+        keyed.sort_by(|(a, _), (b, _)| compare_keys(&specs, a, b));
+
+        for (slot, (_, item)) in items.iter_mut().zip(keyed) {
+            *slot = item;
+        }
+    }
+
+    /// Resolves the keys of a sort stack against the columns, skipping unknown columns.
+    fn resolve_keys(&self, keys: &[SortKey]) -> Vec<ResolvedKey<'_>> {
+        keys.iter()
+            .filter_map(|&(column, order)| {
+                let spec = self.columns.iter().find(|c| c.id() == column)?;
+                Some(ResolvedKey {
+                    column,
+                    order,
+                    kind: spec.kind(),
+                    nulls: spec.nulls(),
+                    sort_key: spec.sort_key(),
+                    comparator: spec.comparator(),
+                    collation: spec.collation().unwrap_or(self.collation),
+                })
+            })
+            .collect()
+    }
+
+    /// Builds the [`SortIndex`] of every row for the sort stack `keys`, ordering rows as
+    /// [`Self::sort_by_keys`] does.
+    pub fn sort_index(&self, keys: &[SortKey]) -> SortIndex {
+        let specs = self.resolve_keys(keys);
+        let mut keyed: Vec<(Vec<CellValue>, usize)> = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| (extract_keys(&specs, row), index))
+            .collect();
+        keyed.sort_by(|(a, _), (b, _)| compare_keys(&specs, a, b));
+
+        let ties = (0..keyed.len())
+            .map(|i| i > 0 && compare_keys(&specs, &keyed[i - 1].0, &keyed[i].0).is_eq())
+            .collect();
+        SortIndex::new(keyed.into_iter().map(|(_, index)| index).collect(), ties)
+    }
+
+    /// Adds the rows appended to the model's rows since `index` was built for the sort stack
+    /// `keys`, e.g. by infinite scrolling.
+    ///
+    /// The new rows are sorted among themselves, then merged into the index by binary search,
+    /// leading to the index [`Self::sort_index`] would build.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::state::SortOrder;
+    ///
+    /// let mut data: Vec<_> = ["b", "d"]
+    ///     .into_iter()
+    ///     .map(|name| maplit::hashmap! { "name" => name.to_string() })
+    ///     .collect();
+    /// let columns = ["name"];
+    /// let keys = [("name", SortOrder::Asc)];
+    /// let mut index = TableModel::new(&data, &columns).sort_index(&keys);
+    ///
+    /// data.extend(["c", "a", "b"].map(|name| maplit::hashmap! { "name" => name.to_string() }));
+    /// let model = TableModel::new(&data, &columns);
+    /// model.extend_sort_index(&mut index, &keys);
+    /// assert_eq!(index.order(), [3, 0, 4, 2, 1]);
+    /// assert_eq!(index, model.sort_index(&keys));
+    /// ```
+    pub fn extend_sort_index(&self, index: &mut SortIndex, keys: &[SortKey]) {
+        let indexed = index.len();
+        if indexed >= self.rows.len() {
+            return;
+        }
+        let specs = self.resolve_keys(keys);
+        let key_of = |row: usize| extract_keys(&specs, &self.rows[row]);
+
+        let mut added: Vec<(Vec<CellValue>, usize)> = (indexed..self.rows.len())
+            .map(|row| (key_of(row), row))
+            .collect();
+        added.sort_by(|(a, _), (b, _)| compare_keys(&specs, a, b));
+
+        // Indexed rows keep their tie with the row before them, unless an added row now is.
+        let mut merged: Vec<(usize, Option<bool>)> = Vec::with_capacity(self.rows.len());
+        let indexed_rows = |positions: Range<usize>| {
+            positions.map(|position| (index.order()[position], Some(index.tie(position))))
+        };
+        let mut copied = 0;
+        for (key, row) in added {
+            // Rows equal to indexed ones come after them, as they come after them in the data.
+            let end = index
+                .order()
+                .partition_point(|&other| compare_keys(&specs, &key_of(other), &key).is_le());
+            merged.extend(indexed_rows(copied..end));
+            copied = end;
+            merged.push((row, None));
+        }
+        merged.extend(indexed_rows(copied..indexed));
+
+        let ties = (0..merged.len())
+            .map(|position| match merged[position] {
+                (_, Some(tie)) if position == 0 || merged[position - 1].1.is_some() => tie,
+                (row, _) => {
+                    position > 0
+                        && compare_keys(&specs, &key_of(merged[position - 1].0), &key_of(row))
+                            .is_eq()
+                }
+            })
+            .collect();
+        *index = SortIndex::new(merged.into_iter().map(|(row, _)| row).collect(), ties);
+    }
+
+    /// Turns the index of a single-column sort into the index of the opposite direction by
+    /// reversing its runs of equal values, keeping empty values where the column wants them.
+    fn reverse_sort_index(&self, index: &mut SortIndex, key: SortKey) {
+        let Some(spec) = self.resolve_keys(&[key]).pop() else {
+            return;
+        };
+        let len = index.len();
+        let is_null = |position: usize| spec.extract(&self.rows[index.order()[position]]).is_null();
+        let positions = match spec.nulls {
+            NullOrder::Last if len > 0 && is_null(len - 1) => {
+                let mut start = len - 1;
+                while start > 0 && index.tie(start) {
+                    start -= 1;
+                }
+                0..start
+            }
+            NullOrder::First if len > 0 && is_null(0) => {
+                let mut end = 1;
+                while end < len && index.tie(end) {
+                    end += 1;
+                }
+                end..len
+            }
+            _ => 0..len,
+        };
+        index.reverse_runs(positions);
+    }
+
+    /// Like [`Self::order`], sorting the matching rows with the [`SortIndex`] of the sort stack
+    /// from `cache` instead of comparing them.
+    ///
+    /// A missing index is built once and cached, or derived from the cached index of the same
+    /// column in the other direction for single-column sorts. Cached indexes are extended with
+    /// the rows appended since; `cache` must be cleared once rows are changed otherwise. Indexes
+    /// built before a change to the collation or to how a column sorts are dropped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::sort_index::SortCache;
+    /// use table_rs::core::state::{SortOrder, TableState};
+    ///
+    /// let data = vec![
+    ///     hashmap! { "name" => "Ferris".to_string() },
+    ///     hashmap! {},
+    ///     hashmap! { "name" => "Crab".to_string() },
+    ///     hashmap! { "name" => "Ferros".to_string() },
+    /// ];
+    /// let columns = ["name"];
+    /// let model = TableModel::new(&data, &columns);
+    /// let mut cache = SortCache::new();
+    ///
+    /// let mut state = TableState { sort: vec![("name", SortOrder::Asc)], ..Default::default() };
+    /// assert_eq!(model.order_cached(&state, &mut cache).indices, [2, 0, 3, 1]);
+    ///
+    /// // Reversed from the cached index, the empty name staying last.
+    /// state.sort = vec![("name", SortOrder::Desc)];
+    /// assert_eq!(model.order_cached(&state, &mut cache).indices, [3, 0, 2, 1]);
+    /// assert_eq!(model.order_cached(&state, &mut cache), model.order(&state));
+    /// ```
+    pub fn order_cached(&self, state: &TableState, cache: &mut SortCache) -> RowOrder {
+        cache.sync_columns(self.column_sorts());
+        let (mut indices, search_error) = self.search_rows(state);
+        self.retain_filtered(&mut indices, |index| &self.rows[index], &state.filters);
+        if !self.resolve_keys(&state.sort).is_empty() {
+            self.cached_sort_index(&state.sort, cache)
+                .apply(&mut indices);
+        }
+        RowOrder {
+            indices,
+            search_error,
+        }
+    }
+
+    /// How each column compares its values when sorting.
+    fn column_sorts(&self) -> Vec<ColumnSort> {
+        self.columns
+            .iter()
+            .map(|spec| ColumnSort {
+                column: spec.id(),
+                kind: spec.kind(),
+                nulls: spec.nulls(),
+                collation: spec.collation().unwrap_or(self.collation),
+                sort_key: spec.sort_key().cloned(),
+                comparator: spec.comparator().cloned(),
+            })
+            .collect()
+    }
+
+    /// Index of the sort stack `keys` over every row, from `cache` when possible.
+    fn cached_sort_index<'c>(&self, keys: &[SortKey], cache: &'c mut SortCache) -> &'c SortIndex {
+        let usable = |index: &SortIndex| index.len() <= self.rows.len();
+        let mut index = match cache.take(keys) {
+            Some(index) if usable(&index) => index,
+            _ => match *keys {
+                [(column, order)] => {
+                    let flipped = (column, order.reversed());
+                    match cache.get(&[flipped]).filter(|index| usable(index)) {
+                        Some(index) => {
+                            let mut index = index.clone();
+                            self.reverse_sort_index(&mut index, flipped);
+                            index
+                        }
+                        None => self.sort_index(keys),
+                    }
+                }
+                _ => self.sort_index(keys),
+            },
+        };
+        self.extend_sort_index(&mut index, keys);
+        cache.insert(keys.to_vec(), index)
+    }
+
+    /// Returns the distinct values of `column` with their row counts, in the column's ascending
+    /// sort order.
+    ///
+    /// Counts cover the rows matching the search and every column filter of `state` except the
+    /// one on `column` itself, so excluded values keep being listed and counts follow the other
+    /// filters. Returns nothing for columns that are not part of the model.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::filter::{ColumnFilter, Facet};
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::state::TableState;
+    ///
+    /// let data = vec![
+    ///     hashmap! { "plan" => "pro".to_string(), "country" => "FR".to_string() },
+    ///     hashmap! { "plan" => "free".to_string(), "country" => "FR".to_string() },
+    ///     hashmap! { "plan" => "pro".to_string(), "country" => "US".to_string() },
+    /// ];
+    /// let columns = ["plan", "country"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let mut state = TableState::default();
+    /// state.filters.insert("plan", ColumnFilter::NoneOf(vec!["free".into()]));
+    /// state.filters.insert("country", ColumnFilter::NoneOf(vec!["US".into()]));
+    ///
+    /// let facets = model.facets("plan", &state);
+    /// assert_eq!(facets, [
+    ///     Facet { value: "free".into(), count: 1 },
+    ///     Facet { value: "pro".into(), count: 1 },
+    /// ]);
+    /// ```
+    pub fn facets(&self, column: &'static str, state: &TableState) -> Vec<Facet> {
+        let Some(spec) = self.columns.iter().find(|c| c.id() == column) else {
+            return Vec::new();
+        };
+        let mut filters = state.filters.clone();
+        filters.remove(column);
+
+        let (mut rows, _) = self.search_rows(state);
+        self.retain_filtered(&mut rows, |index| &self.rows[index], &filters);
+        self.sort_items(
+            &mut rows,
+            |index| &self.rows[index],
+            &[(column, SortOrder::Asc)],
+        );
+
+        let mut facets: Vec<Facet> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for row in self.rows_at(rows) {
+            let value = spec.kind().coerce(row.value(column)).to_string();
+            match index.get(&value) {
+                Some(&i) => facets[i].count += 1,
+                None => {
+                    index.insert(value.clone(), facets.len());
+                    facets.push(Facet { value, count: 1 });
+                }
+            }
+        }
+        facets
+    }
+
+    /// Runs the whole pipeline for `state` and returns the rows of the current page.
+    ///
+    /// In [`SearchMode::Query`], an invalid query is reported in [`TableView::search_error`] and
+    /// does not filter any row. In [`SearchMode::Fuzzy`] with [`TableState::fuzzy_rank`], rows
+    /// are ordered by score unless the sort stack says otherwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::state::{SearchMode, TableState};
+    ///
+    /// let data = vec![
+    ///     hashmap! { "name" => "Fiona Reyes".to_string() },
+    ///     hashmap! { "name" => "Ferris".to_string() },
+    /// ];
+    /// let columns = ["name"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let state = TableState {
+    ///     query: "fr".into(),
+    ///     search_mode: SearchMode::Fuzzy,
+    ///     fuzzy_rank: true,
+    ///     ..Default::default()
+    /// };
+    /// let names: Vec<_> = model.view(&state).rows.iter().map(|row| row["name"].as_str()).collect();
+    /// assert_eq!(names, ["Ferris", "Fiona Reyes"]);
+    /// ```
+    pub fn view(&self, state: &TableState) -> TableView<'a, R> {
+        let order = self.order(state);
+        let total_pages = order.total_pages(state.page_size);
+
+        TableView {
+            rows: self.rows_at(order.page(state.page, state.page_size).to_vec()),
+            total_rows: order.indices.len(),
+            total_pages,
+            page: clamp_page(state.page, total_pages),
+            search_error: order.search_error,
+        }
+    }
+
+    /// Runs the search, filters and sort of `state`, returning the indices of the matching rows
+    /// across all pages; [`TableState::page`] and [`TableState::page_size`] are ignored.
+    ///
+    /// Only the indices are moved around, however large the rows.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::state::{SortOrder, TableState};
+    ///
+    /// let data = vec![
+    ///     hashmap! { "name" => "Ferris".to_string() },
+    ///     hashmap! { "name" => "Crab".to_string() },
+    ///     hashmap! { "name" => "Ferros".to_string() },
+    /// ];
+    /// let columns = ["name"];
+    /// let model = TableModel::new(&data, &columns);
+    ///
+    /// let state = TableState {
+    ///     query: "fer".into(),
+    ///     sort: vec![("name", SortOrder::Desc)],
+    ///     page_size: 1,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(model.order(&state).indices, [2, 0]);
+    /// ```
+    pub fn order(&self, state: &TableState) -> RowOrder {
+        let (mut indices, search_error) = self.search_rows(state);
+        self.retain_filtered(&mut indices, |index| &self.rows[index], &state.filters);
+        self.sort_items(&mut indices, |index| &self.rows[index], &state.sort);
+        RowOrder {
+            indices,
+            search_error,
+        }
+    }
+
+    /// Returns the view of a table whose data is searched, filtered, sorted and paginated
+    /// elsewhere, e.g. by a server: the rows are the current page as given, in order, and the
+    /// page count follows from `total_rows`, the number of matching rows across all pages.
+    ///
+    /// The page is not clamped either: cursor-based sources may not know their page count.
+    ///
+    /// In [`SearchMode::Query`], the query is still parsed so that syntax errors are reported.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use maplit::hashmap;
+    /// use table_rs::core::model::TableModel;
+    /// use table_rs::core::state::TableState;
+    ///
+    /// let page = vec![
+    ///     hashmap! { "name" => "Ferris".to_string() },
+    ///     hashmap! { "name" => "Crab".to_string() },
+    /// ];
+    /// let columns = ["name"];
+    /// let model = TableModel::new(&page, &columns);
+    ///
+    /// let state = TableState { query: "zzz".into(), page: 4, page_size: 2, ..Default::default() };
+    /// let view = model.manual_view(&state, 1_000_001);
+    /// assert_eq!(view.rows.len(), 2);
+    /// assert_eq!(view.total_pages, 500_001);
+    /// ```
+    pub fn manual_view(&self, state: &TableState, total_rows: usize) -> TableView<'a, R> {
+        let search_error = match state.search_mode {
+            SearchMode::Query => self.parse_query(&state.query).err(),
+            _ => None,
+        };
+
+        TableView {
+            rows: self.rows.iter().collect(),
+            total_rows,
+            total_pages: total_pages(total_rows, state.page_size),
+            page: state.page,
+            search_error,
+        }
+    }
+}
+
+/// Compares two cell values in the given direction, keeping empty values where `nulls` says.
+pub fn compare_values(
+    a: &CellValue,
+    b: &CellValue,
+    order: SortOrder,
+    nulls: NullOrder,
+) -> Ordering {
+    compare_with(a, b, order, nulls, Ord::cmp)
+}
+
+/// Like [`compare_values`], ordering non-empty values with `compare` instead of their built-in
+/// ordering.
+fn compare_with(
+    a: &CellValue,
+    b: &CellValue,
+    order: SortOrder,
+    nulls: NullOrder,
+    compare: impl Fn(&CellValue, &CellValue) -> Ordering,
+) -> Ordering {
+    match (a.is_null(), b.is_null()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => match nulls {
+            NullOrder::First => Ordering::Less,
+            NullOrder::Last => Ordering::Greater,
+        },
+        (false, true) => match nulls {
+            NullOrder::First => Ordering::Greater,
+            NullOrder::Last => Ordering::Less,
+        },
+        (false, false) => match order {
+            SortOrder::Asc => compare(a, b),
+            SortOrder::Desc => compare(b, a),
+        },
+    }
+}
+
+/// Number of pages needed to show `total_rows` rows, `page_size` at a time.
+///
+/// There is always at least one page, possibly empty, and a `page_size` of 0 shows every row on
+/// a single page.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::model::total_pages;
+///
+/// assert_eq!(total_pages(532, 20), 27);
+/// assert_eq!(total_pages(0, 20), 1);
+/// assert_eq!(total_pages(532, 0), 1);
+/// ```
+pub fn total_pages(total_rows: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        return 1;
+    }
+    total_rows.div_ceil(page_size).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(names: &[&str]) -> Vec<Row> {
+        names
+            .iter()
+            .map(|name| HashMap::from([("name", name.to_string())]))
+            .collect()
+    }
+
+    /// A column placing empty values first.
+    struct NullsFirst(&'static str);
+
+    impl ColumnSpec for NullsFirst {
+        fn id(&self) -> &'static str {
+            self.0
+        }
+
+        fn nulls(&self) -> NullOrder {
+            NullOrder::First
+        }
+    }
+
+    #[test]
+    fn extended_sort_indexes_keep_ties_in_data_order() {
+        let mut data: Vec<Row> = [("b", "1"), ("a", "2"), ("b", "3")]
+            .iter()
+            .map(|(name, id)| HashMap::from([("name", name.to_string()), ("id", id.to_string())]))
+            .collect();
+        let columns = ["name", "id"];
+        let keys = [("name", SortOrder::Asc)];
+        let mut index = TableModel::new(&data, &columns).sort_index(&keys);
+        assert_eq!(index.order(), [1, 0, 2]);
+
+        data.extend(["b", "a", "c", "b"].map(|name| HashMap::from([("name", name.to_string())])));
+        let model = TableModel::new(&data, &columns);
+        model.extend_sort_index(&mut index, &keys);
+        assert_eq!(index.order(), [1, 4, 0, 2, 3, 6, 5]);
+        assert_eq!(index, model.sort_index(&keys));
+
+        // Ties of the first key are broken by the second one, appended rows included.
+        let keys = [("name", SortOrder::Asc), ("id", SortOrder::Desc)];
+        let mut index = TableModel::new(&data[..3], &columns).sort_index(&keys);
+        model.extend_sort_index(&mut index, &keys);
+        assert_eq!(index.order(), [1, 4, 2, 0, 3, 6, 5]);
+        assert_eq!(index, model.sort_index(&keys));
+    }
+
+    #[test]
+    fn reversed_sort_indexes_keep_leading_nulls_first() {
+        let data = rows(&["b", "", "a", "b", "", "c"]);
+        let columns = [NullsFirst("name")];
+        let model = TableModel::new(&data, &columns);
+
+        let mut index = model.sort_index(&[("name", SortOrder::Asc)]);
+        assert_eq!(index.order(), [1, 4, 2, 0, 3, 5]);
+
+        model.reverse_sort_index(&mut index, ("name", SortOrder::Asc));
+        assert_eq!(index.order(), [1, 4, 5, 0, 3, 2]);
+        assert_eq!(index, model.sort_index(&[("name", SortOrder::Desc)]));
+
+        let mut cache = SortCache::new();
+        for order in [SortOrder::Asc, SortOrder::Desc, SortOrder::Asc] {
+            let state = TableState {
+                sort: vec![("name", order)],
+                ..Default::default()
+            };
+            assert_eq!(model.order_cached(&state, &mut cache), model.order(&state));
+        }
+    }
+
+    #[test]
+    fn switching_collation_drops_cached_sorts() {
+        let data = rows(&["file10", "File2", "file1"]);
+        let columns = ["name"];
+        let state = TableState {
+            sort: vec![("name", SortOrder::Asc)],
+            ..Default::default()
+        };
+        let mut cache = SortCache::new();
+
+        let binary = TableModel::new(&data, &columns);
+        assert_eq!(binary.order_cached(&state, &mut cache).indices, [1, 2, 0]);
+
+        let natural = TableModel::new(&data, &columns).with_collation(Collation::Natural);
+        assert_eq!(
+            natural.order_cached(&state, &mut cache),
+            natural.order(&state)
+        );
+        assert_eq!(natural.order_cached(&state, &mut cache).indices, [2, 1, 0]);
+
+        let mut flipped = state.clone();
+        flipped.sort = vec![("name", SortOrder::Desc)];
+        assert_eq!(binary.order_cached(&flipped, &mut cache).indices, [0, 2, 1]);
+    }
+}
@@ -0,0 +1,76 @@
+//! Text normalization applied by the plain search before matching.
+//!
+//! Both the query and the cells of a column go through the column's [`Normalizer`], and a cell
+//! matches when its normalized text contains the normalized query. The query is normalized once
+//! per column and search, not once per cell.
+
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// How the text of a column and the search query are normalized before being compared.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::normalize::Normalizer;
+///
+/// assert_eq!(Normalizer::CaseFold.normalize("Zoë"), "zoë");
+/// assert_eq!(Normalizer::StripDiacritics.normalize("Zoë Ångström"), "zoe angstrom");
+/// assert_eq!(Normalizer::DigitsOnly.normalize("+1 (555) 010-2030"), "15550102030");
+/// assert_eq!(Normalizer::Exact.normalize("Zoë"), "Zoë");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Normalizer {
+    /// Ignores case (default).
+    #[default]
+    CaseFold,
+    /// Ignores case and diacritics, so `zoe` finds `Zoë`.
+    StripDiacritics,
+    /// Keeps ASCII digits only, so `5550102` finds `(555) 010-2030`. Queries without digits never
+    /// match the column.
+    DigitsOnly,
+    /// Compares text as is.
+    Exact,
+}
+
+impl Normalizer {
+    /// Returns `text` normalized, borrowing it when it is already normalized, e.g. lowercase
+    /// text under [`Normalizer::CaseFold`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use table_rs::core::normalize::Normalizer;
+    ///
+    /// assert!(matches!(Normalizer::CaseFold.normalize("zoë"), Cow::Borrowed("zoë")));
+    /// assert!(matches!(Normalizer::CaseFold.normalize("Zoë"), Cow::Owned(_)));
+    /// assert!(matches!(Normalizer::StripDiacritics.normalize("zoe"), Cow::Borrowed(_)));
+    /// assert!(matches!(Normalizer::DigitsOnly.normalize("555"), Cow::Borrowed(_)));
+    /// ```
+    pub fn normalize(self, text: &str) -> Cow<'_, str> {
+        match self {
+            Normalizer::CaseFold if text.chars().flat_map(char::to_lowercase).eq(text.chars()) => {
+                Cow::Borrowed(text)
+            }
+            Normalizer::CaseFold => Cow::Owned(text.to_lowercase()),
+            Normalizer::StripDiacritics
+                if text.is_ascii() && !text.bytes().any(|b| b.is_ascii_uppercase()) =>
+            {
+                Cow::Borrowed(text)
+            }
+            Normalizer::StripDiacritics => Cow::Owned(
+                text.nfd()
+                    .filter(|c| !is_combining_mark(*c))
+                    .flat_map(char::to_lowercase)
+                    .collect(),
+            ),
+            Normalizer::DigitsOnly if text.bytes().all(|b| b.is_ascii_digit()) => {
+                Cow::Borrowed(text)
+            }
+            Normalizer::DigitsOnly => {
+                Cow::Owned(text.chars().filter(char::is_ascii_digit).collect())
+            }
+            Normalizer::Exact => Cow::Borrowed(text),
+        }
+    }
+}
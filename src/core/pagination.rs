@@ -0,0 +1,129 @@
+//! Helpers behind the rich pagination controls: numbered page windows, row ranges, page jumps
+//! and page size changes.
+//!
+//! Pages are zero-based everywhere, as in [`TableState::page`](crate::core::state::TableState),
+//! and only turned one-based for display.
+
+/// An entry of the numbered page list.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PageItem {
+    /// A link to the zero-based page.
+    Page(usize),
+    /// A gap between non-adjacent pages.
+    Ellipsis,
+}
+
+/// Lists the pages to show around `page`: the first and last pages, and `siblings` pages on each
+/// side of the current one, with ellipses standing for the gaps. A gap of a single page shows
+/// that page instead.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::pagination::{PageItem::*, page_window};
+///
+/// assert_eq!(page_window(0, 3, 1), [Page(0), Page(1), Page(2)]);
+/// assert_eq!(
+///     page_window(9, 27, 1),
+///     [Page(0), Ellipsis, Page(8), Page(9), Page(10), Ellipsis, Page(26)],
+/// );
+/// assert_eq!(page_window(2, 27, 1), [Page(0), Page(1), Page(2), Page(3), Ellipsis, Page(26)]);
+/// assert!(page_window(0, 0, 1).is_empty());
+/// ```
+pub fn page_window(page: usize, total_pages: usize, siblings: usize) -> Vec<PageItem> {
+    let Some(last) = total_pages.checked_sub(1) else {
+        return Vec::new();
+    };
+    let page = page.min(last);
+    let (start, end) = (page.saturating_sub(siblings), (page + siblings).min(last));
+
+    let mut pages = vec![0];
+    pages.extend(start.max(1)..=end);
+    if last > end {
+        pages.push(last);
+    }
+
+    let mut items = Vec::new();
+    let mut previous = None;
+    for page in pages {
+        match previous {
+            Some(previous) if page == previous + 2 => items.push(PageItem::Page(previous + 1)),
+            Some(previous) if page > previous + 2 => items.push(PageItem::Ellipsis),
+            _ => {}
+        }
+        items.push(PageItem::Page(page));
+        previous = Some(page);
+    }
+    items
+}
+
+/// Clamps the zero-based `page` to the last of `total_pages`, e.g. once filtering or new data
+/// left fewer pages than before.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::pagination::clamp_page;
+///
+/// assert_eq!(clamp_page(7, 3), 2);
+/// assert_eq!(clamp_page(1, 3), 1);
+/// assert_eq!(clamp_page(4, 0), 0);
+/// ```
+pub fn clamp_page(page: usize, total_pages: usize) -> usize {
+    page.min(total_pages.saturating_sub(1))
+}
+
+/// One-based, inclusive range of the rows shown on `page`, or `None` when there are none. A
+/// `page_size` of 0 shows every row.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::pagination::row_range;
+///
+/// assert_eq!(row_range(1, 20, 532), Some((21, 40)));
+/// assert_eq!(row_range(26, 20, 532), Some((521, 532)));
+/// assert_eq!(row_range(0, 20, 0), None);
+/// assert_eq!(row_range(0, 0, 532), Some((1, 532)));
+/// ```
+pub fn row_range(page: usize, page_size: usize, total_rows: usize) -> Option<(usize, usize)> {
+    if page_size == 0 {
+        return (total_rows > 0).then_some((1, total_rows));
+    }
+    let start = page.saturating_mul(page_size);
+    (start < total_rows).then(|| (start + 1, start.saturating_add(page_size).min(total_rows)))
+}
+
+/// Parses the one-based page number typed in a "go to page" input into a zero-based page,
+/// clamped to the existing pages.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::pagination::parse_page;
+///
+/// assert_eq!(parse_page("3", 10), Some(2));
+/// assert_eq!(parse_page(" 42 ", 10), Some(9));
+/// assert_eq!(parse_page("0", 10), Some(0));
+/// assert_eq!(parse_page("three", 10), None);
+/// assert_eq!(parse_page("3", 0), None);
+/// ```
+pub fn parse_page(input: &str, total_pages: usize) -> Option<usize> {
+    let number: usize = input.trim().parse().ok()?;
+    let last = total_pages.checked_sub(1)?;
+    Some(number.saturating_sub(1).min(last))
+}
+
+/// Page showing the first row of `page` once rows are shown `new_size` at a time instead of
+/// `old_size`.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::pagination::refit_page;
+///
+/// // Rows 41-50 at 10 per page are on the second page at 25 per page.
+/// assert_eq!(refit_page(4, 10, 25), 1);
+/// assert_eq!(refit_page(1, 25, 10), 2);
+/// ```
+pub fn refit_page(page: usize, old_size: usize, new_size: usize) -> usize {
+    if new_size == 0 {
+        return 0;
+    }
+    page.saturating_mul(old_size) / new_size
+}
@@ -0,0 +1,402 @@
+//! A small query language for the table search.
+//!
+//! Queries are made of whitespace-separated terms, all of which must match a row:
+//!
+//! - `active` keeps rows where any column contains `active`, ignoring case;
+//! - `"test user"` matches a quoted phrase, spaces included;
+//! - `status:active` only looks at the `status` column;
+//! - `age:>30`, `age:>=30`, `age:<30`, `age:<=30` and `status:=active` compare values, as numbers
+//!   or dates when both sides parse as such and as case-insensitive text otherwise;
+//! - `-name:"test user"` negates a term;
+//! - `OR` (in capitals) separates alternatives, and binds looser than the implicit "and" between
+//!   terms: `a b OR c` means `(a and b) or c`.
+//!
+//! [`Query::parse`] reports malformed input as a [`QueryError`] instead of silently matching
+//! nothing, and [`TableModel::parse_query`](crate::core::model::TableModel::parse_query) also
+//! rejects fields that name no column.
+
+use crate::core::value::{CellKind, CellValue};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// How a term compares cell values with its value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Operator {
+    /// The cell contains the value, ignoring case (default).
+    #[default]
+    Contains,
+    /// The cell equals the value (`=`).
+    Eq,
+    /// The cell is greater than the value (`>`).
+    Gt,
+    /// The cell is greater than or equal to the value (`>=`).
+    Ge,
+    /// The cell is less than the value (`<`).
+    Lt,
+    /// The cell is less than or equal to the value (`<=`).
+    Le,
+}
+
+/// One term of a [`Query`], such as `-status:active` or `age:>30`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Term {
+    /// Column the term is scoped to; unscoped terms match any column.
+    pub field: Option<String>,
+    /// How cell values are compared with `value`.
+    pub op: Operator,
+    /// Text of the term, without quotes.
+    pub value: String,
+    /// Whether the term excludes the rows it matches.
+    pub negated: bool,
+    /// Byte offset of the term in the query text.
+    pub position: usize,
+    /// `value` lowercased once at parse time, for case-insensitive matching.
+    folded: String,
+}
+
+impl Term {
+    /// Returns `true` if the cell `value` satisfies the term, ignoring its negation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::query::Query;
+    /// use table_rs::core::value::CellValue;
+    ///
+    /// let query = Query::parse("age:>30 joined:<2024-01-01 name:fer").unwrap();
+    /// let [age, joined, name] = &query.alternatives()[0][..] else { panic!() };
+    ///
+    /// assert!(age.matches(&CellValue::from("42")));
+    /// assert!(!age.matches(&CellValue::Int(30)));
+    /// assert!(joined.matches(&CellValue::from("2023-12-31T23:59:00Z")));
+    /// assert!(name.matches(&CellValue::from("Ferris")));
+    /// assert!(!name.matches(&CellValue::Null));
+    /// ```
+    pub fn matches(&self, value: &CellValue) -> bool {
+        if value.is_null() {
+            return false;
+        }
+        let ordering = match self.op {
+            Operator::Contains => {
+                let text = value.to_string().to_lowercase();
+                return text.contains(&self.folded);
+            }
+            _ => compare_literal(value, &self.value, &self.folded),
+        };
+        ordering.is_some_and(|ordering| match self.op {
+            Operator::Contains | Operator::Eq => ordering.is_eq(),
+            Operator::Gt => ordering.is_gt(),
+            Operator::Ge => ordering.is_ge(),
+            Operator::Lt => ordering.is_lt(),
+            Operator::Le => ordering.is_le(),
+        })
+    }
+}
+
+/// Compares a cell with the text of a term, interpreting the text like the cell; `folded` is the
+/// lowercased text.
+fn compare_literal(value: &CellValue, literal: &str, folded: &str) -> Option<Ordering> {
+    let kind = match value {
+        CellValue::Null => return None,
+        CellValue::Bool(_) => CellKind::Bool,
+        CellValue::Int(_) | CellValue::Float(_) => CellKind::Float,
+        CellValue::Date(_) | CellValue::DateTime(_) => CellKind::DateTime,
+        CellValue::Text(text) => {
+            for kind in [CellKind::Float, CellKind::DateTime] {
+                let (value, literal) = (kind.parse(text), kind.parse(literal));
+                if !value.is_null() && !literal.is_null() {
+                    return Some(value.cmp(&literal));
+                }
+            }
+            return Some(text.to_lowercase().as_str().cmp(folded));
+        }
+    };
+    let literal = kind.parse(literal);
+    (!literal.is_null()).then(|| value.cmp(&literal))
+}
+
+/// A parsed search query: a list of alternatives, each a list of terms that must all match.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::query::{Operator, Query};
+///
+/// let query = Query::parse(r#"status:active age:>30 -name:"test user" OR admin"#).unwrap();
+/// let alternatives = query.alternatives();
+/// assert_eq!(alternatives.len(), 2);
+///
+/// let name = &alternatives[0][2];
+/// assert_eq!(name.field.as_deref(), Some("name"));
+/// assert_eq!(name.value, "test user");
+/// assert!(name.negated);
+/// assert_eq!(alternatives[0][1].op, Operator::Gt);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Query {
+    alternatives: Vec<Vec<Term>>,
+}
+
+impl Query {
+    /// Parses `input`, returning the first syntax error found.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::query::{Query, QueryError};
+    ///
+    /// assert!(Query::parse("").unwrap().is_empty());
+    /// assert_eq!(Query::parse("name:\"ferris"), Err(QueryError::UnterminatedQuote { position: 5 }));
+    /// assert_eq!(Query::parse("age:>"), Err(QueryError::MissingValue { position: 0 }));
+    /// assert_eq!(Query::parse("crab OR"), Err(QueryError::DanglingOr { position: 5 }));
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, QueryError> {
+        let mut parser = Parser { input, pos: 0 };
+        let mut alternatives = vec![Vec::new()];
+        let mut last_or = None;
+
+        while let Some(start) = parser.skip_whitespace() {
+            if parser.take_or() {
+                if alternatives.last().is_some_and(Vec::is_empty) {
+                    return Err(QueryError::DanglingOr { position: start });
+                }
+                alternatives.push(Vec::new());
+                last_or = Some(start);
+                continue;
+            }
+            let term = parser.term()?;
+            if let Some(alternative) = alternatives.last_mut() {
+                alternative.push(term);
+            }
+        }
+
+        match last_or {
+            Some(position) if alternatives.last().is_some_and(Vec::is_empty) => {
+                Err(QueryError::DanglingOr { position })
+            }
+            _ => Ok(Self {
+                alternatives: alternatives.into_iter().filter(|a| !a.is_empty()).collect(),
+            }),
+        }
+    }
+
+    /// Returns `true` if the query has no terms and keeps every row.
+    pub fn is_empty(&self) -> bool {
+        self.alternatives.is_empty()
+    }
+
+    /// Alternatives of the query; a row matches when every term of any alternative matches.
+    pub fn alternatives(&self) -> &[Vec<Term>] {
+        &self.alternatives
+    }
+
+    /// Iterates over every term of the query.
+    pub fn terms(&self) -> impl Iterator<Item = &Term> {
+        self.alternatives.iter().flatten()
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    /// Skips whitespace, returning the position of the next token, if any.
+    fn skip_whitespace(&mut self) -> Option<usize> {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+        (self.pos < self.input.len()).then_some(self.pos)
+    }
+
+    fn take_or(&mut self) -> bool {
+        let rest = self.rest();
+        let is_or =
+            rest.starts_with("OR") && rest[2..].chars().next().is_none_or(char::is_whitespace);
+        if is_or {
+            self.pos += 2;
+        }
+        is_or
+    }
+
+    fn take(&mut self, prefix: &str) -> bool {
+        let taken = self.rest().starts_with(prefix);
+        if taken {
+            self.pos += prefix.len();
+        }
+        taken
+    }
+
+    fn term(&mut self) -> Result<Term, QueryError> {
+        let position = self.pos;
+        let negated = self.rest().starts_with('-')
+            && self.rest()[1..]
+                .chars()
+                .next()
+                .is_some_and(|c| !c.is_whitespace())
+            && self.take("-");
+
+        let rest = self.rest();
+        let word_end = rest
+            .find(|c: char| c.is_whitespace() || c == '"')
+            .unwrap_or(rest.len());
+        let field = match rest[..word_end].find(':') {
+            Some(colon) if colon > 0 => {
+                let field = rest[..colon].to_string();
+                self.pos += colon + 1;
+                Some(field)
+            }
+            _ => None,
+        };
+
+        let op = [
+            (">=", Operator::Ge),
+            ("<=", Operator::Le),
+            (">", Operator::Gt),
+            ("<", Operator::Lt),
+            ("=", Operator::Eq),
+        ]
+        .into_iter()
+        .find(|(prefix, _)| self.take(prefix))
+        .map_or(Operator::Contains, |(_, op)| op);
+
+        let value = if self.rest().starts_with('"') {
+            let quote = self.pos;
+            let rest = &self.rest()[1..];
+            let end = rest
+                .find('"')
+                .ok_or(QueryError::UnterminatedQuote { position: quote })?;
+            self.pos += end + 2;
+            rest[..end].to_string()
+        } else {
+            let rest = self.rest();
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            self.pos += end;
+            rest[..end].to_string()
+        };
+
+        if value.is_empty() {
+            return Err(QueryError::MissingValue { position });
+        }
+
+        Ok(Term {
+            field,
+            op,
+            folded: value.to_lowercase(),
+            value,
+            negated,
+            position,
+        })
+    }
+}
+
+/// Why a search query could not be used.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum QueryError {
+    /// A quoted phrase is never closed.
+    UnterminatedQuote {
+        /// Byte offset of the opening quote.
+        position: usize,
+    },
+    /// A term has a field or an operator but no value, as in `status:` or `age:>`.
+    MissingValue {
+        /// Byte offset of the term.
+        position: usize,
+    },
+    /// `OR` has nothing on one of its sides.
+    DanglingOr {
+        /// Byte offset of the `OR`.
+        position: usize,
+    },
+    /// A term is scoped to a field that names no column.
+    UnknownField {
+        /// The field as written in the query.
+        field: String,
+        /// Byte offset of the term.
+        position: usize,
+    },
+}
+
+impl QueryError {
+    /// Byte offset in the query text where the error was found.
+    pub fn position(&self) -> usize {
+        match self {
+            QueryError::UnterminatedQuote { position }
+            | QueryError::MissingValue { position }
+            | QueryError::DanglingOr { position }
+            | QueryError::UnknownField { position, .. } => *position,
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::UnterminatedQuote { position } => {
+                write!(f, "unterminated quote at position {position}")
+            }
+            QueryError::MissingValue { position } => {
+                write!(f, "missing value for the term at position {position}")
+            }
+            QueryError::DanglingOr { position } => {
+                write!(f, "`OR` at position {position} is missing an operand")
+            }
+            QueryError::UnknownField { field, position } => {
+                write!(f, "unknown field `{field}` at position {position}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_queries_report_where_they_fail() {
+        let cases = [
+            (
+                "name:\"ferris",
+                QueryError::UnterminatedQuote { position: 5 },
+            ),
+            (
+                "say \"hi there",
+                QueryError::UnterminatedQuote { position: 4 },
+            ),
+            ("status:", QueryError::MissingValue { position: 0 }),
+            ("crab -age:<=", QueryError::MissingValue { position: 5 }),
+            ("OR crab", QueryError::DanglingOr { position: 0 }),
+            ("crab OR OR ferris", QueryError::DanglingOr { position: 8 }),
+            ("crab OR", QueryError::DanglingOr { position: 5 }),
+        ];
+        for (input, error) in cases {
+            assert_eq!(Query::parse(input), Err(error.clone()), "{input}");
+            assert_eq!(
+                Query::parse(input).unwrap_err().position(),
+                error.position()
+            );
+        }
+    }
+
+    #[test]
+    fn look_alikes_of_operators_are_plain_terms() {
+        let query = Query::parse("crab or - ORacle").unwrap();
+        let values: Vec<_> = query.terms().map(|term| term.value.as_str()).collect();
+        assert_eq!(values, ["crab", "or", "-", "ORacle"]);
+        assert_eq!(query.alternatives().len(), 1);
+        assert!(
+            query
+                .terms()
+                .all(|term| !term.negated && term.field.is_none())
+        );
+    }
+
+    #[test]
+    fn errors_display_their_position() {
+        let error = Query::parse("a OR").unwrap_err();
+        assert!(error.to_string().contains("position 2"), "{error}");
+    }
+}
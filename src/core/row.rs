@@ -0,0 +1,97 @@
+use crate::core::value::CellValue;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Column metadata generated by `#[derive(TableRow)]`.
+///
+/// Every framework's `Column` type implements `From<ColumnMeta>`, so a typed row can describe
+/// its own columns through [`TableRow::columns`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ColumnMeta {
+    /// Unique identifier of the column; the field name unless renamed.
+    pub id: &'static str,
+
+    /// Header text displayed at the top of the column.
+    pub header: &'static str,
+
+    /// Whether the column is sortable.
+    pub sortable: bool,
+}
+
+/// A row of table data whose cells can be looked up by column id.
+///
+/// `HashMap<&'static str, String>` implements this trait, which keeps the string-map rows used
+/// throughout the examples working. Structs usually derive it instead:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use table_rs::TableRow;
+/// use table_rs::core::row::{ColumnMeta, TableRow as _};
+/// use table_rs::core::value::CellValue;
+///
+/// #[derive(Clone, PartialEq, TableRow)]
+/// struct User {
+///     #[table(sortable)]
+///     name: String,
+///     #[table(header = "Email", sortable)]
+///     email: String,
+///     #[table(skip)]
+///     password_hash: String,
+///     age: u32,
+/// }
+///
+/// let user = User {
+///     name: "Ferris".into(),
+///     email: "ferris@opensass.org".into(),
+///     password_hash: String::new(),
+///     age: 12,
+/// };
+///
+/// assert_eq!(user.cell("email").as_deref(), Some("ferris@opensass.org"));
+/// assert_eq!(user.cell("age").as_deref(), Some("12"));
+/// assert_eq!(user.cell("password_hash"), None);
+/// assert_eq!(user.value("age"), CellValue::Int(12));
+///
+/// let columns: Vec<ColumnMeta> = User::columns();
+/// assert_eq!(columns.len(), 3);
+/// assert_eq!(columns[1], ColumnMeta { id: "email", header: "Email", sortable: true });
+/// assert_eq!(columns[2].header, "Age");
+/// # }
+/// ```
+pub trait TableRow {
+    /// Returns the text of the cell in `column`, or `None` if the row has no such column.
+    fn cell(&self, column: &str) -> Option<Cow<'_, str>>;
+
+    /// Returns the typed value of the cell in `column`, used for sorting.
+    ///
+    /// Defaults to the cell text, or [`CellValue::Null`] when the cell is missing.
+    fn value(&self, column: &str) -> CellValue {
+        self.cell(column)
+            .map_or(CellValue::Null, |text| CellValue::Text(text.into_owned()))
+    }
+
+    /// Describes the columns of this row type, in declaration order.
+    ///
+    /// Rows without a fixed shape, such as string maps, return no columns.
+    fn column_meta() -> Vec<ColumnMeta>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// Builds the column list of this row type for any framework's `Column`.
+    fn columns<C: From<ColumnMeta>>() -> Vec<C>
+    where
+        Self: Sized,
+    {
+        Self::column_meta().into_iter().map(C::from).collect()
+    }
+}
+
+impl TableRow for HashMap<&'static str, String> {
+    fn cell(&self, column: &str) -> Option<Cow<'_, str>> {
+        self.get(column).map(|value| Cow::Borrowed(value.as_str()))
+    }
+}
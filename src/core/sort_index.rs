@@ -0,0 +1,188 @@
+//! Pre-built sort permutations, reused across searches, filters and pages.
+//!
+//! Sorting is the costly step of the pipeline on large tables. A [`SortIndex`] holds the order of
+//! every row of a table for one sort stack, so sorting the rows left by a search or filter only
+//! keeps them in that order. A [`SortCache`] holds the indexes of the last few sort stacks:
+//! going back to a previous stack reuses its index, flipping the direction of a single-column
+//! sort reverses the index instead of sorting again, and rows appended to the data are merged
+//! into the indexes rather than sorted along with the others. Changing how columns sort, e.g.
+//! the table's collation, drops the cached indexes.
+//!
+//! Indexes are built and updated by [`TableModel`], which knows how rows compare; see
+//! [`TableModel::order_cached`].
+//!
+//! [`TableModel`]: crate::core::model::TableModel
+//! [`TableModel::order_cached`]: crate::core::model::TableModel::order_cached
+
+use crate::core::compare::{Collation, Comparator, SortKeyFn};
+use crate::core::state::SortKey;
+use crate::core::value::{CellKind, NullOrder};
+use std::ops::Range;
+
+/// Number of sort stacks whose index a [`SortCache`] keeps.
+pub const MAX_CACHED_SORTS: usize = 4;
+
+/// The rows of a table in the order of a sort stack, as indices into the rows. Rows comparing
+/// equal keep their original order.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::model::TableModel;
+/// use table_rs::core::state::SortOrder;
+///
+/// let data: Vec<_> = ["b", "a", "c", "a"]
+///     .into_iter()
+///     .map(|name| maplit::hashmap! { "name" => name.to_string() })
+///     .collect();
+/// let columns = ["name"];
+/// let index = TableModel::new(&data, &columns).sort_index(&[("name", SortOrder::Asc)]);
+/// assert_eq!(index.order(), [1, 3, 0, 2]);
+///
+/// // Rows left by a search, in sorted order.
+/// let mut rows = vec![0, 2, 3];
+/// index.apply(&mut rows);
+/// assert_eq!(rows, [3, 0, 2]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct SortIndex {
+    order: Vec<usize>,
+    ties: Vec<bool>,
+}
+
+impl SortIndex {
+    /// An index over rows in `order`, where `ties[i]` tells whether the row at position `i`
+    /// compares equal to the one before it.
+    pub(crate) fn new(order: Vec<usize>, ties: Vec<bool>) -> Self {
+        debug_assert_eq!(order.len(), ties.len());
+        Self { order, ties }
+    }
+
+    /// Indices of every row, sorted.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Number of rows indexed.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns `true` when no row is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Whether the row at `position` compares equal to the one before it.
+    pub(crate) fn tie(&self, position: usize) -> bool {
+        self.ties[position]
+    }
+
+    /// Puts `indices`, given in their original order, in sorted order. Indices of rows that are
+    /// not indexed are dropped.
+    pub fn apply(&self, indices: &mut Vec<usize>) {
+        let mut kept = vec![false; self.order.len()];
+        for &index in indices.iter() {
+            if let Some(kept) = kept.get_mut(index) {
+                *kept = true;
+            }
+        }
+        indices.clear();
+        indices.extend(self.order.iter().copied().filter(|&index| kept[index]));
+    }
+
+    /// Reverses the order of the runs of equal rows within `positions`, keeping the order of
+    /// the rows of each run, e.g. to sort the other way while empty values stay in place.
+    ///
+    /// `positions` must start and end on a run boundary.
+    pub(crate) fn reverse_runs(&mut self, positions: Range<usize>) {
+        let mut runs = Vec::new();
+        let mut start = positions.start;
+        for position in positions.start + 1..positions.end {
+            if !self.ties[position] {
+                runs.push(start..position);
+                start = position;
+            }
+        }
+        if start < positions.end {
+            runs.push(start..positions.end);
+        }
+
+        let mut order = Vec::with_capacity(positions.len());
+        let mut ties = Vec::with_capacity(positions.len());
+        for run in runs.into_iter().rev() {
+            ties.push(false);
+            ties.extend(std::iter::repeat_n(true, run.len() - 1));
+            order.extend_from_slice(&self.order[run]);
+        }
+        self.order.splice(positions.clone(), order);
+        self.ties.splice(positions, ties);
+    }
+}
+
+/// How a column compares its values when sorting, as resolved by
+/// [`TableModel`](crate::core::model::TableModel).
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct ColumnSort {
+    pub(crate) column: &'static str,
+    pub(crate) kind: CellKind,
+    pub(crate) nulls: NullOrder,
+    pub(crate) collation: Collation,
+    pub(crate) sort_key: Option<SortKeyFn>,
+    pub(crate) comparator: Option<Comparator>,
+}
+
+/// The sort indexes of the last [`MAX_CACHED_SORTS`] sort stacks over the same rows.
+///
+/// The cache does not see the rows: it must be cleared once they change, unless rows were only
+/// appended, in which case indexes are extended when next used. It does see how the columns
+/// sort, and forgets its indexes once that changes.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SortCache {
+    indexes: Vec<(Vec<SortKey>, SortIndex)>,
+    columns: Vec<ColumnSort>,
+}
+
+impl SortCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets every index, e.g. once rows were changed or removed.
+    pub fn clear(&mut self) {
+        self.indexes.clear();
+    }
+
+    /// Forgets every index unless the columns sort as they did when the indexes were built.
+    pub(crate) fn sync_columns(&mut self, columns: Vec<ColumnSort>) {
+        if self.columns != columns {
+            self.indexes.clear();
+            self.columns = columns;
+        }
+    }
+
+    /// Index of the sort stack `keys`, if cached.
+    pub fn get(&self, keys: &[SortKey]) -> Option<&SortIndex> {
+        self.indexes
+            .iter()
+            .find(|(cached, _)| cached == keys)
+            .map(|(_, index)| index)
+    }
+
+    /// Removes the index of the sort stack `keys` from the cache and returns it.
+    pub fn take(&mut self, keys: &[SortKey]) -> Option<SortIndex> {
+        let position = self.indexes.iter().position(|(cached, _)| cached == keys)?;
+        Some(self.indexes.remove(position).1)
+    }
+
+    /// Caches the index of the sort stack `keys`, dropping the least recently cached one when
+    /// full, and returns it.
+    pub fn insert(&mut self, keys: Vec<SortKey>, index: SortIndex) -> &SortIndex {
+        self.indexes.retain(|(cached, _)| *cached != keys);
+        if self.indexes.len() == MAX_CACHED_SORTS {
+            self.indexes.remove(0);
+        }
+        self.indexes.push((keys, index));
+        &self.indexes[self.indexes.len() - 1].1
+    }
+}
@@ -0,0 +1,169 @@
+//! Asynchronous data sources, fetching one page of rows at a time.
+//!
+//! A [`DataSource`] receives the [`TableState`] to fetch (search, filters, sort and page) and
+//! resolves to the matching [`Page`]. Framework tables take a type-erased [`SharedSource`] and
+//! fetch a new page whenever the state changes, ignoring the responses of stale requests.
+//!
+//! [`MemorySource`] implements the trait over rows held in memory, running the regular
+//! [`TableModel`] pipeline, which makes it handy for tests and prototypes.
+
+use crate::core::model::{ColumnSpec, TableModel};
+use crate::core::row::TableRow;
+use crate::core::state::TableState;
+use std::convert::Infallible;
+use std::fmt;
+use std::future::{Future, ready};
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// One page of rows returned by a [`DataSource`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Page<T> {
+    /// Rows of the requested page, in order.
+    pub rows: Vec<T>,
+    /// Number of rows matching the search and filters across all pages.
+    pub total_rows: usize,
+    /// Whether a next page exists, for cursor-based sources that cannot count the matching rows;
+    /// when set, `total_rows` is ignored and the pagination shows no total.
+    pub has_next: Option<bool>,
+}
+
+/// Fetches pages of rows, e.g. from a server.
+///
+/// Sources backed by cursor (keyset) pagination receive page indices too: they can remember the
+/// cursor returned with each page and resume from the one of `query.page - 1`, answering with
+/// [`Page::has_next`] instead of a row count.
+pub trait DataSource<T> {
+    /// Why a page could not be fetched; shown in the table's error row.
+    type Error: fmt::Display;
+
+    /// Fetches the rows of `query.page` matching the search and filters of `query`, sorted
+    /// according to its sort stack.
+    fn fetch(&self, query: &TableState) -> impl Future<Output = Result<Page<T>, Self::Error>>;
+}
+
+/// The future returned by [`SharedSource::fetch`], with the error turned into its message.
+pub type FetchFuture<T> = Pin<Box<dyn Future<Output = Result<Page<T>, String>>>>;
+
+/// A cheaply clonable, type-erased [`DataSource`], as taken by the table components.
+///
+/// Two handles are equal when they share the same source.
+///
+/// # Examples
+/// ```rust
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// use table_rs::core::source::{MemorySource, SharedSource};
+/// use table_rs::core::state::TableState;
+///
+/// let rows: Vec<_> = (1..=25).map(|n| maplit::hashmap! { "n" => n.to_string() }).collect();
+/// let source = SharedSource::new(MemorySource::new(rows, vec!["n"]));
+/// assert_eq!(source, source.clone());
+///
+/// let query = TableState { page: 2, ..Default::default() };
+/// let mut fetch = pin!(source.fetch(query));
+/// let Poll::Ready(Ok(page)) = fetch.as_mut().poll(&mut Context::from_waker(Waker::noop())) else {
+///     panic!("memory sources resolve immediately");
+/// };
+/// assert_eq!(page.rows.len(), 5);
+/// assert_eq!(page.total_rows, 25);
+/// ```
+pub struct SharedSource<T>(Rc<dyn Fn(TableState) -> FetchFuture<T>>);
+
+impl<T: 'static> SharedSource<T> {
+    /// Wraps a data source.
+    pub fn new<S: DataSource<T> + 'static>(source: S) -> Self {
+        let source = Rc::new(source);
+        Self(Rc::new(move |query| {
+            let source = source.clone();
+            Box::pin(async move {
+                source
+                    .fetch(&query)
+                    .await
+                    .map_err(|error| error.to_string())
+            })
+        }))
+    }
+
+    /// Fetches the page described by `query`.
+    pub fn fetch(&self, query: TableState) -> FetchFuture<T> {
+        (self.0)(query)
+    }
+}
+
+impl<T> Clone for SharedSource<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for SharedSource<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> fmt::Debug for SharedSource<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedSource(..)")
+    }
+}
+
+/// A [`DataSource`] serving rows held in memory, searched, filtered, sorted and paginated like
+/// the table would do it.
+///
+/// # Examples
+/// ```rust
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// use maplit::hashmap;
+/// use table_rs::core::source::{DataSource, MemorySource};
+/// use table_rs::core::state::{SortOrder, TableState};
+///
+/// let source = MemorySource::new(
+///     vec![
+///         hashmap! { "name" => "Ferris".to_string() },
+///         hashmap! { "name" => "Crab".to_string() },
+///         hashmap! { "name" => "Ferros".to_string() },
+///     ],
+///     vec!["name"],
+/// );
+///
+/// let query = TableState {
+///     query: "fer".into(),
+///     sort: vec![("name", SortOrder::Desc)],
+///     ..Default::default()
+/// };
+/// let mut fetch = pin!(source.fetch(&query));
+/// let Poll::Ready(Ok(page)) = fetch.as_mut().poll(&mut Context::from_waker(Waker::noop())) else {
+///     panic!("memory sources resolve immediately");
+/// };
+/// let names: Vec<_> = page.rows.iter().map(|row| row["name"].as_str()).collect();
+/// assert_eq!(names, ["Ferros", "Ferris"]);
+/// assert_eq!(page.total_rows, 2);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct MemorySource<T, C = &'static str> {
+    rows: Vec<T>,
+    columns: Vec<C>,
+}
+
+impl<T, C> MemorySource<T, C> {
+    /// Serves `rows`, searching and sorting them according to `columns`.
+    pub fn new(rows: Vec<T>, columns: Vec<C>) -> Self {
+        Self { rows, columns }
+    }
+}
+
+impl<T: TableRow + Clone, C: ColumnSpec> DataSource<T> for MemorySource<T, C> {
+    type Error = Infallible;
+
+    fn fetch(&self, query: &TableState) -> impl Future<Output = Result<Page<T>, Infallible>> {
+        let view = TableModel::new(&self.rows, &self.columns).view(query);
+        ready(Ok(Page {
+            rows: view.rows.into_iter().cloned().collect(),
+            total_rows: view.total_rows,
+            has_next: None,
+        }))
+    }
+}
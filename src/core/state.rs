@@ -0,0 +1,235 @@
+use crate::core::filter::{ColumnFilter, ColumnFilters, set_filter};
+
+/// Sort direction (ascending or descending).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SortOrder {
+    /// Ascending (default).
+    #[default]
+    Asc,
+    /// Descending.
+    Desc,
+}
+
+impl SortOrder {
+    /// Returns the opposite sort direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            SortOrder::Asc => SortOrder::Desc,
+            SortOrder::Desc => SortOrder::Asc,
+        }
+    }
+
+    /// Returns the matching `aria-sort` attribute value.
+    pub fn aria(self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ascending",
+            SortOrder::Desc => "descending",
+        }
+    }
+}
+
+/// How repeated clicks on a header cycle through sort states.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SortCycle {
+    /// Ascending, descending, ascending... (default).
+    #[default]
+    Toggle,
+    /// Ascending, descending, then unsorted, restoring the original data order.
+    TriState,
+}
+
+impl SortCycle {
+    /// Returns the direction following `order`, or `None` when the column becomes unsorted.
+    pub fn next(self, order: SortOrder) -> Option<SortOrder> {
+        match (self, order) {
+            (SortCycle::TriState, SortOrder::Desc) => None,
+            _ => Some(order.reversed()),
+        }
+    }
+}
+
+/// How the search query is interpreted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SearchMode {
+    /// Rows where any column contains the query, ignoring case (default).
+    #[default]
+    Plain,
+    /// The query language of [`crate::core::query`], with field-scoped terms, quoted phrases,
+    /// negation, comparisons and `OR`.
+    Query,
+    /// Typo-tolerant matching of each query word, see [`crate::core::fuzzy`].
+    Fuzzy,
+}
+
+/// One key of a sort stack: a column id and the direction to sort it in.
+pub type SortKey = (&'static str, SortOrder);
+
+/// Default maximum number of keys in a sort stack.
+pub const DEFAULT_MAX_SORT_KEYS: usize = 3;
+
+/// Applies a click on the header of `column` to the sort stack `sort`.
+///
+/// A plain click sorts by `column` alone, moving it to its next state in `cycle` if it was
+/// already sorted. An `additive` click (shift-click) does the same for `column` in place when it
+/// is part of the stack, or appends it as the lowest priority key. Once the stack holds
+/// `max_keys` keys, the lowest priority one is replaced instead.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::state::{SortCycle, SortOrder, toggle_sort_key};
+///
+/// let cycle = SortCycle::Toggle;
+/// let mut sort = Vec::new();
+/// toggle_sort_key(&mut sort, "department", false, 2, cycle);
+/// toggle_sort_key(&mut sort, "salary", true, 2, cycle);
+/// toggle_sort_key(&mut sort, "salary", true, 2, cycle);
+/// assert_eq!(sort, [("department", SortOrder::Asc), ("salary", SortOrder::Desc)]);
+///
+/// toggle_sort_key(&mut sort, "name", true, 2, cycle);
+/// assert_eq!(sort, [("department", SortOrder::Asc), ("name", SortOrder::Asc)]);
+///
+/// toggle_sort_key(&mut sort, "department", false, 2, cycle);
+/// assert_eq!(sort, [("department", SortOrder::Desc)]);
+///
+/// toggle_sort_key(&mut sort, "department", false, 2, SortCycle::TriState);
+/// assert!(sort.is_empty());
+/// ```
+pub fn toggle_sort_key(
+    sort: &mut Vec<SortKey>,
+    column: &'static str,
+    additive: bool,
+    max_keys: usize,
+    cycle: SortCycle,
+) {
+    let existing = sort.iter().position(|(id, _)| *id == column);
+
+    if !additive {
+        let order = existing.map_or(Some(SortOrder::Asc), |i| cycle.next(sort[i].1));
+        sort.clear();
+        sort.extend(order.map(|order| (column, order)));
+        return;
+    }
+
+    match existing {
+        Some(i) => match cycle.next(sort[i].1) {
+            Some(order) => sort[i].1 = order,
+            None => {
+                sort.remove(i);
+            }
+        },
+        None => {
+            sort.truncate(max_keys.max(1) - 1);
+            sort.push((column, SortOrder::Asc));
+        }
+    }
+}
+
+/// Returns the one-based priority and direction of `column` in the sort stack, if sorted.
+pub fn sort_priority(sort: &[SortKey], column: &str) -> Option<(usize, SortOrder)> {
+    sort.iter()
+        .position(|(id, _)| *id == column)
+        .map(|i| (i + 1, sort[i].1))
+}
+
+/// The interactive settings applied to the table data: search query, filters, sort and page.
+///
+/// `TableState` is a plain value; framework components keep it (or its fields) in their own
+/// reactive state and hand it to [`TableModel::view`](crate::core::model::TableModel::view)
+/// on every render.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::state::{SortOrder, TableState};
+///
+/// let mut state = TableState { page: 3, ..Default::default() };
+///
+/// state.toggle_sort("department", false);
+/// state.toggle_sort("salary", true);
+/// state.toggle_sort("salary", true);
+/// assert_eq!(state.sort, [("department", SortOrder::Asc), ("salary", SortOrder::Desc)]);
+///
+/// state.set_query("ferris");
+/// assert_eq!(state.page, 0);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableState {
+    /// Global search query matched against every column.
+    pub query: String,
+
+    /// Whether `query` is plain text, uses the query language or is matched fuzzily.
+    pub search_mode: SearchMode,
+
+    /// Whether fuzzy search results are ordered by score while the sort stack is empty.
+    pub fuzzy_rank: bool,
+
+    /// Column filters, all of which rows must pass in addition to the search query.
+    pub filters: ColumnFilters,
+
+    /// Sort stack, highest priority first; rows tied on a key are ordered by the next one.
+    pub sort: Vec<SortKey>,
+
+    /// Maximum number of keys kept in the sort stack.
+    pub max_sort_keys: usize,
+
+    /// How repeated header clicks cycle through sort states.
+    pub sort_cycle: SortCycle,
+
+    /// Zero-based index of the current page.
+    pub page: usize,
+
+    /// Number of rows per page.
+    pub page_size: usize,
+}
+
+impl Default for TableState {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            search_mode: SearchMode::Plain,
+            fuzzy_rank: false,
+            filters: ColumnFilters::new(),
+            sort: Vec::new(),
+            max_sort_keys: DEFAULT_MAX_SORT_KEYS,
+            sort_cycle: SortCycle::Toggle,
+            page: 0,
+            page_size: 10,
+        }
+    }
+}
+
+impl TableState {
+    /// Applies a click on the header of `column`; see [`toggle_sort_key`].
+    pub fn toggle_sort(&mut self, column: &'static str, additive: bool) {
+        toggle_sort_key(
+            &mut self.sort,
+            column,
+            additive,
+            self.max_sort_keys,
+            self.sort_cycle,
+        );
+    }
+
+    /// Sets or clears the filter of `column` and goes back to the first page.
+    pub fn set_filter(&mut self, column: &'static str, filter: Option<ColumnFilter>) {
+        set_filter(&mut self.filters, column, filter);
+        self.page = 0;
+    }
+
+    /// Replaces the search query and goes back to the first page.
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+        self.page = 0;
+    }
+
+    /// Moves to the previous page, staying on the first one.
+    pub fn prev_page(&mut self) {
+        self.page = self.page.saturating_sub(1);
+    }
+
+    /// Moves to the next page, staying on the last one.
+    pub fn next_page(&mut self, total_pages: usize) {
+        if self.page + 1 < total_pages {
+            self.page += 1;
+        }
+    }
+}
@@ -0,0 +1,500 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A calendar date, ordered chronologically.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Date {
+    /// Year, e.g. `2025`.
+    pub year: i32,
+    /// Month of the year, from `1` to `12`.
+    pub month: u8,
+    /// Day of the month, from `1` to `31`.
+    pub day: u8,
+}
+
+impl Date {
+    /// Parses an ISO 8601 date such as `2025-04-30`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().splitn(3, '-');
+        let (year, month, day) = match (parts.next(), parts.next(), parts.next()) {
+            // Leading `-` for negative years is not supported; neither are years without 4 digits.
+            (Some(y), Some(m), Some(d)) if y.len() == 4 && m.len() == 2 && d.len() == 2 => {
+                (y.parse().ok()?, m.parse().ok()?, d.parse().ok()?)
+            }
+            _ => return None,
+        };
+        let date = Self { year, month, day };
+        date.is_valid().then_some(date)
+    }
+
+    fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && self.day >= 1
+            && self.day <= days_in_month(self.year, self.month)
+    }
+
+    /// Number of days since `1970-01-01`.
+    fn days_since_epoch(&self) -> i64 {
+        // Howard Hinnant's `days_from_civil`.
+        let y = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = i64::from(self.month);
+        let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    fn from_days_since_epoch(days: i64) -> Self {
+        // Howard Hinnant's `civil_from_days`.
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A date and time of day in UTC, ordered chronologically.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct DateTime {
+    /// Calendar date.
+    pub date: Date,
+    /// Hour of the day, from `0` to `23`.
+    pub hour: u8,
+    /// Minute of the hour, from `0` to `59`.
+    pub minute: u8,
+    /// Second of the minute, from `0` to `59`.
+    pub second: u8,
+    /// Fraction of the second, in nanoseconds.
+    pub nanosecond: u32,
+}
+
+impl DateTime {
+    /// Parses an ISO 8601 date-time such as `2025-04-30T13:45:00Z`.
+    ///
+    /// The separator may be `T` or a space, seconds and fractions are optional, and a trailing
+    /// `Z` or `±HH:MM` offset is converted to UTC. A bare date is read as midnight.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.len() == 10 {
+            return Date::parse(text).map(Self::from);
+        }
+        if text.len() < 16 || !matches!(text.as_bytes()[10], b'T' | b't' | b' ') {
+            return None;
+        }
+
+        let date = Date::parse(&text[..10])?;
+        let rest = &text[11..];
+        let (time, offset_minutes) = split_offset(rest)?;
+
+        let mut fields = time.splitn(3, ':');
+        let hour: u8 = fields.next()?.parse().ok()?;
+        let minute: u8 = fields.next()?.parse().ok()?;
+        let (second, nanosecond) = match fields.next() {
+            Some(seconds) => {
+                let (whole, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+                let second: u8 = whole.parse().ok()?;
+                if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                let digits = &fraction[..fraction.len().min(9)];
+                let nanosecond = if digits.is_empty() {
+                    0
+                } else {
+                    digits.parse::<u32>().ok()? * 10_u32.pow(9 - digits.len() as u32)
+                };
+                (second, nanosecond)
+            }
+            None => (0, 0),
+        };
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        let local = Self {
+            date,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        };
+        Some(local.shifted_minutes(-offset_minutes))
+    }
+
+    fn shifted_minutes(self, minutes: i64) -> Self {
+        if minutes == 0 {
+            return self;
+        }
+        let total = self.date.days_since_epoch() * 1440
+            + i64::from(self.hour) * 60
+            + i64::from(self.minute)
+            + minutes;
+        let minute_of_day = total.rem_euclid(1440);
+        Self {
+            date: Date::from_days_since_epoch(total.div_euclid(1440)),
+            hour: (minute_of_day / 60) as u8,
+            minute: (minute_of_day % 60) as u8,
+            ..self
+        }
+    }
+}
+
+impl From<Date> for DateTime {
+    fn from(date: Date) -> Self {
+        Self {
+            date,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        }
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond > 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        f.write_str("Z")
+    }
+}
+
+/// Splits a `HH:MM[:SS[.fff]]` time from its trailing UTC offset, returned in minutes. Offsets
+/// beyond `±23:59` are rejected.
+fn split_offset(text: &str) -> Option<(&str, i64)> {
+    if let Some(time) = text.strip_suffix(['Z', 'z']) {
+        return Some((time, 0));
+    }
+    match text.rfind(['+', '-']) {
+        Some(index) => {
+            let (time, offset) = text.split_at(index);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let (hours, minutes) = offset[1..].split_once(':').unwrap_or((&offset[1..], "00"));
+            let hours = hours.parse::<u8>().ok().filter(|hours| *hours <= 23)?;
+            let minutes = minutes
+                .parse::<u8>()
+                .ok()
+                .filter(|minutes| *minutes <= 59)?;
+            let (hours, minutes) = (i64::from(hours), i64::from(minutes));
+            Some((time, sign * (hours * 60 + minutes)))
+        }
+        None => Some((text, 0)),
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A typed cell value, used to sort columns by their meaning rather than their text.
+///
+/// Values of different types compare in a fixed order: booleans, then numbers, then dates and
+/// date-times, then text. Integers and floats compare numerically with each other, and a date
+/// compares as midnight of that day against a date-time.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::value::{CellKind, CellValue};
+///
+/// assert!(CellValue::Int(18) < CellValue::Int(100));
+/// assert!(CellValue::Text("100".into()) < CellValue::Text("18".into()));
+/// assert!(CellValue::Float(2.5) < CellValue::Int(3));
+///
+/// assert_eq!(CellKind::Int.parse("42"), CellValue::Int(42));
+/// assert_eq!(CellKind::Bool.parse("Yes"), CellValue::Bool(true));
+/// assert_eq!(CellKind::Date.parse("2025-02-30"), CellValue::Null);
+/// assert_eq!(CellKind::DateTime.parse("2025-01-01T01:30:00+02:00").to_string(), "2024-12-31T23:30:00Z");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub enum CellValue {
+    /// Missing, empty or unparseable value.
+    #[default]
+    Null,
+    /// Boolean value.
+    Bool(bool),
+    /// Integer value.
+    Int(i64),
+    /// Floating point value.
+    Float(f64),
+    /// Calendar date.
+    Date(Date),
+    /// Date and time of day.
+    DateTime(DateTime),
+    /// Plain text.
+    Text(String),
+}
+
+impl CellValue {
+    /// Returns `true` for [`CellValue::Null`].
+    pub fn is_null(&self) -> bool {
+        matches!(self, CellValue::Null)
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            CellValue::Null => 0,
+            CellValue::Bool(_) => 1,
+            CellValue::Int(_) | CellValue::Float(_) => 2,
+            CellValue::Date(_) | CellValue::DateTime(_) => 3,
+            CellValue::Text(_) => 4,
+        }
+    }
+}
+
+impl PartialEq for CellValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CellValue {}
+
+impl PartialOrd for CellValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CellValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use CellValue::*;
+        match (self, other) {
+            (Bool(a), Bool(b)) => a.cmp(b),
+            (Int(a), Int(b)) => a.cmp(b),
+            (Float(a), Float(b)) => a.total_cmp(b),
+            (Int(a), Float(b)) => (*a as f64).total_cmp(b),
+            (Float(a), Int(b)) => a.total_cmp(&(*b as f64)),
+            (Date(a), Date(b)) => a.cmp(b),
+            (DateTime(a), DateTime(b)) => a.cmp(b),
+            (Date(a), DateTime(b)) => crate::core::value::DateTime::from(*a).cmp(b),
+            (DateTime(a), Date(b)) => a.cmp(&crate::core::value::DateTime::from(*b)),
+            (Text(a), Text(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellValue::Null => Ok(()),
+            CellValue::Bool(value) => value.fmt(f),
+            CellValue::Int(value) => value.fmt(f),
+            CellValue::Float(value) => value.fmt(f),
+            CellValue::Date(value) => value.fmt(f),
+            CellValue::DateTime(value) => value.fmt(f),
+            CellValue::Text(value) => value.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for CellValue {
+                fn from(value: $ty) -> Self {
+                    i64::try_from(value)
+                        .map(CellValue::Int)
+                        .unwrap_or(CellValue::Float(value as f64))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<f32> for CellValue {
+    fn from(value: f32) -> Self {
+        CellValue::Float(f64::from(value))
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        CellValue::Float(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        CellValue::Text(value)
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        CellValue::Text(value.to_string())
+    }
+}
+
+impl From<Date> for CellValue {
+    fn from(value: Date) -> Self {
+        CellValue::Date(value)
+    }
+}
+
+impl From<DateTime> for CellValue {
+    fn from(value: DateTime) -> Self {
+        CellValue::DateTime(value)
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(CellValue::Null, Into::into)
+    }
+}
+
+/// How the values of a column are interpreted when sorting.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CellKind {
+    /// Use the values as the row provides them (default): text for string maps, typed values for
+    /// rows deriving `TableRow`.
+    #[default]
+    Auto,
+    /// Compare values as text.
+    Text,
+    /// Parse text as integers, falling back to floats.
+    Int,
+    /// Parse text as floating point numbers.
+    Float,
+    /// Parse `true`/`false`, `yes`/`no` and `1`/`0`.
+    Bool,
+    /// Parse ISO 8601 dates (`YYYY-MM-DD`).
+    Date,
+    /// Parse ISO 8601 date-times (`YYYY-MM-DDTHH:MM:SS`, with optional offset).
+    DateTime,
+}
+
+impl CellKind {
+    /// Parses `text` as a value of this kind; empty or unparseable text becomes
+    /// [`CellValue::Null`].
+    pub fn parse(self, text: &str) -> CellValue {
+        let trimmed = text.trim();
+        match self {
+            _ if text.is_empty() => CellValue::Null,
+            CellKind::Auto | CellKind::Text => CellValue::Text(text.to_string()),
+            CellKind::Int => trimmed
+                .parse::<i64>()
+                .map(CellValue::Int)
+                .or_else(|_| trimmed.parse::<f64>().map(CellValue::Float))
+                .unwrap_or(CellValue::Null),
+            CellKind::Float => trimmed
+                .parse::<f64>()
+                .map(CellValue::Float)
+                .unwrap_or(CellValue::Null),
+            CellKind::Bool => match trimmed.to_ascii_lowercase().as_str() {
+                "true" | "yes" | "1" => CellValue::Bool(true),
+                "false" | "no" | "0" => CellValue::Bool(false),
+                _ => CellValue::Null,
+            },
+            CellKind::Date => Date::parse(trimmed).map_or(CellValue::Null, CellValue::Date),
+            CellKind::DateTime => {
+                DateTime::parse(trimmed).map_or(CellValue::Null, CellValue::DateTime)
+            }
+        }
+    }
+
+    /// Converts a row value to this kind: text is parsed, other values are kept as they are
+    /// unless the kind is [`CellKind::Text`]. Empty text is [`CellValue::Null`] whatever the kind,
+    /// so that empty cells follow the column's [`NullOrder`].
+    pub fn coerce(self, value: CellValue) -> CellValue {
+        match (self, value) {
+            (_, CellValue::Text(text)) if text.is_empty() => CellValue::Null,
+            (CellKind::Auto, value) | (_, value @ CellValue::Null) => value,
+            (CellKind::Text, CellValue::Text(text)) => CellValue::Text(text),
+            (CellKind::Text, value) => CellValue::Text(value.to_string()),
+            (kind, CellValue::Text(text)) => kind.parse(&text),
+            (_, value) => value,
+        }
+    }
+}
+
+/// Where empty values go when sorting, whatever the sort direction.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum NullOrder {
+    /// Empty values come before every other value.
+    First,
+    /// Empty values come after every other value (default).
+    #[default]
+    Last,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_shift_date_times_to_utc() {
+        assert_eq!(split_offset("01:30:00+02:00"), Some(("01:30:00", 120)));
+        assert_eq!(split_offset("01:30:00-0530"), None);
+        assert_eq!(split_offset("01:30:00-05:30"), Some(("01:30:00", -330)));
+        assert_eq!(split_offset("01:30:00+05"), Some(("01:30:00", 300)));
+        assert_eq!(split_offset("01:30:00Z"), Some(("01:30:00", 0)));
+        assert_eq!(split_offset("01:30:00"), Some(("01:30:00", 0)));
+    }
+
+    #[test]
+    fn out_of_range_offsets_are_rejected() {
+        assert_eq!(split_offset("00:00+24:00"), None);
+        assert_eq!(split_offset("00:00+23:60"), None);
+        assert_eq!(split_offset("00:00+99:99"), None);
+        assert_eq!(split_offset("00:00+999999999999999999:00"), None);
+        assert_eq!(
+            CellKind::DateTime.parse("2025-01-01T00:00+999999999999999999:00"),
+            CellValue::Null,
+        );
+        assert!(!CellKind::DateTime.parse("2025-01-01T00:00+23:59").is_null());
+    }
+
+    #[test]
+    fn empty_text_is_null() {
+        for kind in [
+            CellKind::Auto,
+            CellKind::Text,
+            CellKind::Int,
+            CellKind::Date,
+        ] {
+            assert!(kind.parse("").is_null(), "{kind:?}");
+            assert!(
+                kind.coerce(CellValue::Text(String::new())).is_null(),
+                "{kind:?}"
+            );
+        }
+        assert_eq!(
+            CellKind::Auto.coerce(CellValue::Text(" ".into())),
+            CellValue::Text(" ".into()),
+        );
+    }
+}
@@ -0,0 +1,405 @@
+//! Row and column virtualization: which rows of a long body, and which columns of a wide table,
+//! to render for the current scroll position.
+//!
+//! Only the rows intersecting the viewport, plus a few "overscan" rows on each side, are
+//! rendered. Two spacer rows stand for the others, keeping the scroll height of the full body.
+//! Rows either share a fixed height, or are measured once rendered, with unmeasured rows
+//! counting for an estimate.
+
+use crate::core::data::SharedRows;
+use std::collections::BTreeMap;
+
+/// Height assumed for rows, in pixels, unless told otherwise.
+pub const DEFAULT_ROW_HEIGHT: f64 = 36.0;
+
+/// Whether a body showing `indices` of `rows` (every row when `None`) still shows the rows it
+/// showed with `previous_indices` of `previous_rows` at the same positions, followed by new ones,
+/// as when infinite scroll appends rows.
+///
+/// Heights are measured by position, so they only stay valid across such appends; any other
+/// change, e.g. a new sort, search or page, may put other rows at the measured positions.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::data::SharedRows;
+/// use table_rs::core::virtualize::shows_appended_rows;
+///
+/// let rows: SharedRows<&str> = vec!["b", "a"].into();
+/// let more = rows.appended(["c"]);
+/// assert!(shows_appended_rows(&rows, None, &more, None));
+///
+/// // Sorted: the appended row lands in the middle.
+/// let sorted: SharedRows<usize> = vec![1, 0].into();
+/// let resorted: SharedRows<usize> = vec![1, 0, 2].into();
+/// assert!(shows_appended_rows(&rows, Some(&sorted), &more, Some(&resorted)));
+/// let resorted: SharedRows<usize> = vec![0, 1, 2].into();
+/// assert!(!shows_appended_rows(&rows, Some(&sorted), &more, Some(&resorted)));
+/// ```
+pub fn shows_appended_rows<T>(
+    previous_rows: &SharedRows<T>,
+    previous_indices: Option<&SharedRows<usize>>,
+    rows: &SharedRows<T>,
+    indices: Option<&SharedRows<usize>>,
+) -> bool {
+    rows.extends(previous_rows)
+        && match (previous_indices, indices) {
+            (None, None) => true,
+            (Some(previous), Some(indices)) => previous == indices || indices.starts_with(previous),
+            _ => false,
+        }
+}
+
+/// Rows of a virtualized body to render, along with the room taken by the others.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct VirtualWindow {
+    /// Index of the first row to render.
+    pub start: usize,
+    /// Index past the last row to render.
+    pub end: usize,
+    /// Height of the rows above `start`, in pixels.
+    pub before: f64,
+    /// Height of the rows from `end` on, in pixels.
+    pub after: f64,
+}
+
+/// Heights of the rows of a virtualized body, in pixels.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::virtualize::RowHeights;
+///
+/// let mut heights = RowHeights::new(30.0);
+/// assert_eq!(heights.offset(4), 120.0);
+///
+/// // The second row wraps onto two lines.
+/// assert!(heights.measure(1, 60.0));
+/// assert!(!heights.measure(1, 60.0));
+/// assert_eq!(heights.offset(4), 150.0);
+/// assert_eq!(heights.total(10), 330.0);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct RowHeights {
+    estimate: f64,
+    measured: BTreeMap<usize, f64>,
+}
+
+impl Default for RowHeights {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROW_HEIGHT)
+    }
+}
+
+impl RowHeights {
+    /// Rows of `height` pixels, the estimate of unmeasured rows when rows are measured. Heights
+    /// below one pixel count as one pixel.
+    pub fn new(height: f64) -> Self {
+        Self {
+            estimate: height.max(1.0),
+            measured: BTreeMap::new(),
+        }
+    }
+
+    /// Records the rendered height of row `index`, returning whether it changed.
+    pub fn measure(&mut self, index: usize, height: f64) -> bool {
+        if self.height(index) == height {
+            return false;
+        }
+        self.measured.insert(index, height);
+        true
+    }
+
+    /// Forgets the measured heights, e.g. once the rows changed.
+    pub fn clear(&mut self) {
+        self.measured.clear();
+    }
+
+    /// Whether any row height was measured.
+    pub fn is_measured(&self) -> bool {
+        !self.measured.is_empty()
+    }
+
+    /// Height of row `index`, measured or estimated.
+    pub fn height(&self, index: usize) -> f64 {
+        self.measured.get(&index).copied().unwrap_or(self.estimate)
+    }
+
+    /// Distance from the top of the body to row `index`.
+    pub fn offset(&self, index: usize) -> f64 {
+        let corrections: f64 = self
+            .measured
+            .range(..index)
+            .map(|(_, height)| height - self.estimate)
+            .sum();
+        index as f64 * self.estimate + corrections
+    }
+
+    /// Height of a body of `rows` rows.
+    pub fn total(&self, rows: usize) -> f64 {
+        self.offset(rows)
+    }
+
+    /// Index of the row found `y` pixels below the top of a body of `rows` rows, clamped to the
+    /// last one.
+    fn locate(&self, y: f64, rows: usize) -> usize {
+        let last = rows.saturating_sub(1);
+        let (mut next, mut top) = (0, 0.0);
+        for (&index, &height) in &self.measured {
+            let gap = (index - next) as f64 * self.estimate;
+            if y < top + gap {
+                break;
+            }
+            top += gap;
+            if y < top + height {
+                return index.min(last);
+            }
+            top += height;
+            next = index + 1;
+        }
+        let skipped = ((y - top).max(0.0) / self.estimate) as usize;
+        next.saturating_add(skipped).min(last)
+    }
+
+    /// Rows of a body of `rows` rows to render when it is scrolled `scroll_top` pixels down in a
+    /// viewport `viewport_height` pixels tall, with `overscan` more rows on each side.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::virtualize::{RowHeights, VirtualWindow};
+    ///
+    /// let heights = RowHeights::new(20.0);
+    /// let window = heights.window(1_000_000, 10_000.0, 400.0, 5);
+    /// assert_eq!(
+    ///     window,
+    ///     VirtualWindow { start: 495, end: 526, before: 9_900.0, after: 19_989_480.0 },
+    /// );
+    ///
+    /// assert_eq!(heights.window(0, 0.0, 400.0, 5), VirtualWindow::default());
+    /// ```
+    pub fn window(
+        &self,
+        rows: usize,
+        scroll_top: f64,
+        viewport_height: f64,
+        overscan: usize,
+    ) -> VirtualWindow {
+        if rows == 0 {
+            return VirtualWindow::default();
+        }
+        let first = self.locate(scroll_top, rows);
+        let last = self.locate(scroll_top + viewport_height.max(0.0), rows);
+        let start = first.saturating_sub(overscan);
+        let end = last.saturating_add(overscan + 1).min(rows);
+        VirtualWindow {
+            start,
+            end,
+            before: self.offset(start),
+            after: self.total(rows) - self.offset(end),
+        }
+    }
+}
+
+/// Width assumed for columns without a minimum width, in pixels.
+pub const DEFAULT_COLUMN_WIDTH: f64 = 100.0;
+
+/// Columns of a horizontally virtualized table to render.
+///
+/// Pinned columns are always rendered, first and stuck to the left edge. The other columns scroll
+/// under them, and only those in view, plus `overscan` on each side, are rendered.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ColumnWindow {
+    /// Indices and widths of the pinned columns.
+    pub pinned: Vec<(usize, f64)>,
+    /// Indices and widths of the scrolling columns in view, in order.
+    pub visible: Vec<(usize, f64)>,
+    /// Width of the scrolling columns left of `visible`, in pixels.
+    pub before: f64,
+    /// Width of the scrolling columns right of `visible`, in pixels.
+    pub after: f64,
+}
+
+/// A cell of a header or body row in a horizontally virtualized table.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ColumnSlot<'a, C> {
+    /// A column to render, with the inline style sizing it and sticking it when pinned.
+    Column(&'a C, String),
+    /// An empty cell taking the room of the columns not rendered, with the inline style sizing it.
+    Spacer(String),
+}
+
+impl ColumnWindow {
+    /// Columns to render when the table is scrolled `scroll_left` pixels right in a viewport
+    /// `viewport_width` pixels wide, given the width and whether each column is pinned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use table_rs::core::virtualize::ColumnWindow;
+    ///
+    /// // A pinned name column, then one 50 pixels wide column per day of the year.
+    /// let mut columns = vec![(150.0, true)];
+    /// columns.extend([(50.0, false); 365]);
+    ///
+    /// let window = ColumnWindow::new(&columns, 1_000.0, 450.0, 1);
+    /// assert_eq!(window.pinned, [(0, 150.0)]);
+    /// let visible: Vec<_> = window.visible.iter().map(|(index, _)| *index).collect();
+    /// assert_eq!(visible, (20..=27).collect::<Vec<_>>());
+    /// assert_eq!((window.before, window.after), (950.0, 16_900.0));
+    /// ```
+    pub fn new(
+        columns: &[(f64, bool)],
+        scroll_left: f64,
+        viewport_width: f64,
+        overscan: usize,
+    ) -> Self {
+        let (pinned, scrolling): (Vec<_>, Vec<_>) = columns
+            .iter()
+            .enumerate()
+            .map(|(index, &(width, pinned))| ((index, width.max(1.0)), pinned))
+            .partition(|(_, pinned)| *pinned);
+        let pinned: Vec<_> = pinned.into_iter().map(|(column, _)| column).collect();
+        let scrolling: Vec<_> = scrolling.into_iter().map(|(column, _)| column).collect();
+
+        let pinned_width: f64 = pinned.iter().map(|(_, width)| width).sum();
+        let right = scroll_left + (viewport_width - pinned_width).max(0.0);
+        let mut offset = 0.0;
+        let mut shown = None::<(usize, usize)>;
+        for (position, (_, width)) in scrolling.iter().enumerate() {
+            if offset + width > scroll_left && offset < right {
+                shown = Some((shown.map_or(position, |(first, _)| first), position));
+            }
+            offset += width;
+        }
+        let total = offset;
+
+        let Some((first, last)) = shown else {
+            return Self {
+                pinned,
+                visible: Vec::new(),
+                before: total,
+                after: 0.0,
+            };
+        };
+        let start = first.saturating_sub(overscan);
+        let end = last.saturating_add(overscan + 1).min(scrolling.len());
+        let before: f64 = scrolling[..start].iter().map(|(_, width)| width).sum();
+        let visible = scrolling[start..end].to_vec();
+        let after = total - before - visible.iter().map(|(_, width)| width).sum::<f64>();
+        Self {
+            pinned,
+            visible,
+            before,
+            after,
+        }
+    }
+
+    /// Cells of a row of `columns` to render, in order.
+    pub fn slots<'a, C>(&self, columns: &'a [C]) -> Vec<ColumnSlot<'a, C>> {
+        let size =
+            |width: f64| format!("width: {width}px; min-width: {width}px; max-width: {width}px;");
+        let mut slots = Vec::new();
+        let mut left = 0.0;
+        for &(index, width) in &self.pinned {
+            let style = format!(
+                "{} position: sticky; left: {left}px; z-index: 1;",
+                size(width)
+            );
+            slots.extend(
+                columns
+                    .get(index)
+                    .map(|column| ColumnSlot::Column(column, style)),
+            );
+            left += width;
+        }
+        if self.before > 0.0 {
+            slots.push(ColumnSlot::Spacer(size(self.before)));
+        }
+        for &(index, width) in &self.visible {
+            slots.extend(
+                columns
+                    .get(index)
+                    .map(|column| ColumnSlot::Column(column, size(width))),
+            );
+        }
+        if self.after > 0.0 {
+            slots.push(ColumnSlot::Spacer(size(self.after)));
+        }
+        slots
+    }
+}
+
+/// Cells of a row of `columns` to render: those of `window` when virtualized, every column
+/// otherwise.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::virtualize::{ColumnSlot, ColumnWindow, column_slots};
+///
+/// let columns = ["name", "mon", "tue", "wed"];
+/// assert_eq!(column_slots(&columns, None).len(), 4);
+///
+/// let widths = [(100.0, true), (80.0, false), (80.0, false), (80.0, false)];
+/// let window = ColumnWindow::new(&widths, 0.0, 200.0, 0);
+/// let slots = column_slots(&columns, Some(&window));
+/// assert!(matches!(slots[..], [
+///     ColumnSlot::Column(&"name", _),
+///     ColumnSlot::Column(&"mon", _),
+///     ColumnSlot::Column(&"tue", _),
+///     ColumnSlot::Spacer(_),
+/// ]));
+/// ```
+pub fn column_slots<'a, C>(
+    columns: &'a [C],
+    window: Option<&ColumnWindow>,
+) -> Vec<ColumnSlot<'a, C>> {
+    match window {
+        Some(window) => window.slots(columns),
+        None => columns
+            .iter()
+            .map(|column| ColumnSlot::Column(column, String::new()))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurements_survive_appends_only() {
+        let rows: SharedRows<u32> = vec![3, 1, 2].into();
+        let more = rows.appended([0]);
+        let copy: SharedRows<u32> = vec![3, 1, 2, 0].into();
+        let page: SharedRows<usize> = vec![0, 1].into();
+
+        // Same rows, unsorted, or rows appended to them.
+        assert!(shows_appended_rows(&rows, None, &rows, None));
+        assert!(shows_appended_rows(&rows, None, &more, None));
+        assert!(shows_appended_rows(&rows, Some(&page), &rows, Some(&page)));
+        let longer: SharedRows<usize> = vec![0, 1, 2].into();
+        assert!(shows_appended_rows(
+            &rows,
+            Some(&page),
+            &more,
+            Some(&longer)
+        ));
+
+        // New data, even equal rows collected anew.
+        assert!(!shows_appended_rows(&rows, None, &copy, None));
+        // Same rows re-sorted, filtered or paged to as many rows.
+        let sorted: SharedRows<usize> = vec![1, 2].into();
+        assert!(!shows_appended_rows(
+            &rows,
+            Some(&page),
+            &rows,
+            Some(&sorted)
+        ));
+        // Rows removed, or switching between every row and some of them.
+        assert!(!shows_appended_rows(
+            &rows,
+            Some(&longer),
+            &rows,
+            Some(&page)
+        ));
+        assert!(!shows_appended_rows(&rows, None, &rows, Some(&page)));
+        assert!(!shows_appended_rows(&rows, Some(&page), &rows, None));
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod body;
 pub mod controls;
+pub mod filters;
 pub mod header;
 pub mod table;
 pub mod types;
@@ -1,8 +1,16 @@
+use crate::core::data::SharedRows;
+use crate::core::fuzzy::{highlight_ranges, split_highlights};
+use crate::core::row::TableRow;
+use crate::core::virtualize::{
+    ColumnSlot, ColumnWindow, DEFAULT_ROW_HEIGHT, RowHeights, VirtualWindow, column_slots,
+    shows_appended_rows,
+};
 use crate::dioxus::types::Column;
 use crate::dioxus::types::TableClasses;
 use crate::dioxus::types::TableTexts;
 use dioxus::prelude::*;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 /// A table body component that renders rows of data, along with loading and empty states.
 ///
@@ -11,15 +19,26 @@ use std::collections::HashMap;
 ///
 /// # Props
 /// - `columns`: A `Vec<Column>` defining which fields to render in each table row. Each column corresponds to a key in the row data.
-/// - `rows`: A `Vec<HashMap<&'static str, String>>` representing the data for each row, where keys match column IDs.
+/// - `rows`: A `SharedRows<T>` representing the data for each row, where `T: TableRow` exposes cells by column ID; a `Vec<T>` converts into it.
+/// - `indices`: The indices into `rows` of the rows to display, in order; every row when `None` (optional).
 /// - `loading`: A `bool` flag that, when true, displays a loading message instead of data rows.
 /// - `classes`: A `TableClasses` struct for customizing the CSS class names of the body, rows, and cells.
 /// - `texts`: A `TableTexts` struct that provides custom text for the loading and empty states.
+/// - `highlight`: A fuzzy search query whose matches are wrapped in `<mark>` elements styled by `classes.highlight` (optional).
+/// - `error`: The message of a failed fetch; when set, an error row is shown instead of the data (optional).
+/// - `on_retry`: An `EventHandler<()>` called when the retry button of the error row is clicked (optional).
+/// - `has_more`: A `bool` ending the rows with a "loading more" row styled by `classes.loading_row` (optional).
+/// - `on_load_more`: An `EventHandler<()>` called whenever that row scrolls into view (optional).
+/// - `virtualize`: A `bool` rendering only the rows between `scroll_top` and `scroll_top + viewport_height`, plus `overscan` rows on each side, with spacer rows styled by `classes.spacer_row` standing for the others (optional).
+/// - `column_window`: The columns to render when they are virtualized, with spacer cells standing for the others; every column when `None` (optional).
+/// - `row_height`: The height of each row in pixels, or their estimate when `measure_rows` measures the rendered rows (optional).
 ///
 /// # Behavior
 /// - If `loading` is `true`, a single row with a loading message is shown spanning all columns.
-/// - If `rows` is empty and not loading, an empty message row is displayed.
-/// - Otherwise, each data row is rendered in a `<tr>`, with one `<td>` per column.
+/// - If `error` is set and not loading, a row with `texts.error` and a retry button is displayed.
+/// - If there are no rows to display, not loading and `has_more` is `false`, an empty message row is displayed.
+/// - Otherwise, each data row is rendered in a `<tr>`, with one `<td>` per column, followed by the
+///   "loading more" row if `has_more` is `true`.
 ///
 /// # Returns
 /// A Dioxus `Element` representing the `<tbody>` of a table, with dynamic row content.
@@ -59,45 +78,156 @@ use std::collections::HashMap;
 /// # See Also
 /// - [MDN `<tbody>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/tbody)
 #[component]
-pub fn TableBody(
+pub fn TableBody<T: TableRow + Clone + PartialEq + 'static>(
     columns: Vec<Column>,
-    rows: Vec<HashMap<&'static str, String>>,
+    #[props(into)] rows: SharedRows<T>,
+    #[props(default)] indices: Option<SharedRows<usize>>,
     loading: bool,
     classes: TableClasses,
     texts: TableTexts,
+    #[props(default)] highlight: String,
+    #[props(default)] error: Option<String>,
+    #[props(default)] on_retry: Option<EventHandler<()>>,
+    #[props(default)] has_more: bool,
+    #[props(default)] on_load_more: Option<EventHandler<()>>,
+    #[props(default)] virtualize: bool,
+    #[props(default = DEFAULT_ROW_HEIGHT)] row_height: f64,
+    #[props(default)] measure_rows: bool,
+    #[props(default = 5)] overscan: usize,
+    #[props(default)] scroll_top: f64,
+    #[props(default = 480.0)] viewport_height: f64,
+    #[props(default)] column_window: Option<ColumnWindow>,
 ) -> Element {
+    let mut heights = use_signal(|| RowHeights::new(row_height));
+    let row_count = indices.as_ref().map_or(rows.len(), |indices| indices.len());
+    let row_at = |position: usize| &rows[indices.as_ref().map_or(position, |i| i[position])];
+    use_effect(use_reactive!(|row_height| {
+        heights.set(RowHeights::new(row_height));
+    }));
+    // Measurements are kept as rows are appended, e.g. by infinite scroll, and dropped once other
+    // rows may be shown at the measured positions.
+    let shown = use_hook(|| Rc::new(RefCell::new((rows.clone(), indices.clone()))));
+    let (shown_rows, shown_indices) = (rows.clone(), indices.clone());
+    use_effect(use_reactive!(|(shown_rows, shown_indices)| {
+        let mut shown = shown.borrow_mut();
+        let (rows, indices) = &*shown;
+        if !shows_appended_rows(rows, indices.as_ref(), &shown_rows, shown_indices.as_ref())
+            && heights.peek().is_measured()
+        {
+            heights.write().clear();
+        }
+        *shown = (shown_rows, shown_indices);
+    }));
+    let window = if virtualize {
+        heights
+            .read()
+            .window(row_count, scroll_top, viewport_height, overscan)
+    } else {
+        VirtualWindow {
+            end: row_count,
+            ..Default::default()
+        }
+    };
+
+    let slots = column_slots(&columns, column_window.as_ref());
+
     let content = if loading {
         rsx! {
             tr { class: "{classes.loading_row}",
                 td {
-                    colspan: "{columns.len()}",
+                    colspan: "{slots.len()}",
                     "{texts.loading}"
                 }
             }
         }
-    } else if rows.is_empty() {
+    } else if let Some(error) = error {
+        rsx! {
+            tr { class: "{classes.error_row}", role: "alert",
+                td {
+                    colspan: "{slots.len()}",
+                    title: "{error}",
+                    "{texts.error}"
+                    button {
+                        class: "{classes.retry_button}",
+                        onclick: move |_| {
+                            if let Some(on_retry) = on_retry {
+                                on_retry.call(());
+                            }
+                        },
+                        "{texts.retry}"
+                    }
+                }
+            }
+        }
+    } else if row_count == 0 && !has_more {
         rsx! {
             tr { class: "{classes.empty_row}",
                 td {
-                    colspan: "{columns.len()}",
+                    colspan: "{slots.len()}",
                     "{texts.empty}"
                 }
             }
         }
     } else {
         rsx! {
-            for row in rows.iter() {
-                tr { class: "{classes.row}", role: "row",
-                    for col in columns.iter() {
-                        td { class: "{classes.body_cell}", role: "cell",
-                            BodyCell {
-                                column: col.clone(),
-                                content: row.get(col.id).unwrap_or(&String::new()),
-                            }
+            if window.before > 0.0 {
+                tr {
+                    class: "{classes.spacer_row}",
+                    "aria-hidden": "true",
+                    style: "height: {window.before}px",
+                }
+            }
+            for (index, row) in (window.start..window.end).map(|i| (i, row_at(i))) {
+                tr {
+                    key: "{index}",
+                    class: "{classes.row}",
+                    role: "row",
+                    // Observed rows report their height once rendered and whenever it changes.
+                    onresize: move |e| {
+                        if let (true, Ok(size)) = (virtualize && measure_rows, e.get_border_box_size())
+                            && heights.peek().height(index) != size.height
+                        {
+                            heights.write().measure(index, size.height);
+                        }
+                    },
+                    for slot in slots.iter() {
+                        match slot {
+                            ColumnSlot::Column(col, style) => rsx! {
+                                td { class: "{classes.body_cell}", role: "cell", style: "{style}",
+                                    BodyCell {
+                                        column: (*col).clone(),
+                                        content: row.cell(col.id).unwrap_or_default().into_owned(),
+                                        highlight: highlight.clone(),
+                                        mark_class: classes.highlight,
+                                    }
+                                }
+                            },
+                            ColumnSlot::Spacer(style) => rsx! {
+                                td { "aria-hidden": "true", style: "{style}" }
+                            },
                         }
                     }
                 }
             }
+            if window.after > 0.0 {
+                tr {
+                    class: "{classes.spacer_row}",
+                    "aria-hidden": "true",
+                    style: "height: {window.after}px",
+                }
+            }
+            if has_more {
+                // Keyed by the row count: once rows were added, the row is re-created along with
+                // its observer, which reports whether it is still visible, so loading goes on
+                // until the rows fill the viewport.
+                LoadMoreRow {
+                    key: "{row_count}",
+                    class: classes.loading_row,
+                    colspan: slots.len(),
+                    text: texts.loading_more,
+                    on_load_more,
+                }
+            }
         }
     };
 
@@ -108,13 +238,51 @@ pub fn TableBody(
     }
 }
 
+/// The "loading more" row ending the rows, calling `on_load_more` whenever it scrolls into view.
+#[component]
+fn LoadMoreRow(
+    class: &'static str,
+    colspan: usize,
+    text: &'static str,
+    on_load_more: Option<EventHandler<()>>,
+) -> Element {
+    rsx! {
+        tr {
+            class: "{class}",
+            onvisible: move |e| {
+                if let (Ok(true), Some(on_load_more)) = (e.is_intersecting(), on_load_more) {
+                    on_load_more.call(());
+                }
+            },
+            td { colspan: "{colspan}", "{text}" }
+        }
+    }
+}
+
 #[component]
-fn BodyCell(column: Column, content: String) -> Element {
+fn BodyCell(
+    column: Column,
+    content: String,
+    highlight: String,
+    mark_class: &'static str,
+) -> Element {
     if let Some(cb) = column.cell {
         cb(content)
     } else {
+        let ranges = highlight_ranges(&highlight, &content);
+        let segments = split_highlights(&content, &ranges)
+            .into_iter()
+            .map(|(segment, marked)| {
+                if marked {
+                    rsx! {
+                        mark { class: "{mark_class}", "{segment}" }
+                    }
+                } else {
+                    rsx! { "{segment}" }
+                }
+            });
         rsx! {
-            "{content}"
+            {segments}
         }
     }
 }
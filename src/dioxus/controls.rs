@@ -1,47 +1,153 @@
+use crate::core::pagination::{PageItem, page_window, parse_page, row_range};
 use crate::dioxus::types::TableClasses;
 use crate::dioxus::types::TableTexts;
 use dioxus::prelude::*;
 
 #[component]
 pub fn PaginationControls(
-    page: Signal<usize>,
+    page: usize,
+    on_page_change: EventHandler<usize>,
     total_pages: usize,
+    #[props(default)] has_next: Option<bool>,
+    #[props(default)] rich: bool,
+    #[props(default = 10)] page_size: usize,
+    #[props(default)] page_sizes: Vec<usize>,
+    #[props(default)] on_page_size_change: Option<EventHandler<usize>>,
+    #[props(default)] total_rows: Option<usize>,
     classes: TableClasses,
     texts: TableTexts,
 ) -> Element {
+    let has_next_page = has_next.unwrap_or(page + 1 < total_pages);
+    let indicator = match has_next {
+        Some(_) => texts.cursor_indicator,
+        None => texts.page_indicator,
+    };
+    // Numbered pages, "Last" and the page jump need a known page count.
+    let counted = rich && has_next.is_none();
+
     let on_prev = move |_| {
-        if page() > 0 {
-            page.set(page() - 1);
+        if page > 0 {
+            on_page_change.call(page - 1);
         }
     };
 
     let on_next = move |_| {
-        if page() + 1 < total_pages {
-            page.set(page() + 1);
+        if has_next_page {
+            on_page_change.call(page + 1);
         }
     };
 
+    let range = total_rows.filter(|_| counted).and_then(|total| {
+        let (start, end) = row_range(page, page_size, total)?;
+        Some(
+            texts
+                .range_indicator
+                .replace("{start}", &start.to_string())
+                .replace("{end}", &end.to_string())
+                .replace("{total}", &total.to_string()),
+        )
+    });
+
     rsx! {
         div { class: classes.pagination,
+            if rich {
+                button {
+                    class: classes.pagination_button,
+                    onclick: move |_| on_page_change.call(0),
+                    disabled: page == 0,
+                    "{texts.first_button}"
+                }
+            }
             button {
                 class: classes.pagination_button,
                 onclick: on_prev,
-                disabled: page() == 0,
+                disabled: page == 0,
                 "{texts.previous_button}"
             }
-            span {
-                {
-                    texts.page_indicator
-                        .replace("{current}", &(page() + 1).to_string())
-                        .replace("{total}", &total_pages.to_string())
+            if counted {
+                span {
+                    for item in page_window(page, total_pages, 1) {
+                        match item {
+                            PageItem::Page(target) if target == page => rsx! {
+                                button {
+                                    class: "{classes.page_button} {classes.page_button_active}",
+                                    "aria-current": "page",
+                                    disabled: true,
+                                    "{target + 1}"
+                                }
+                            },
+                            PageItem::Page(target) => rsx! {
+                                button {
+                                    class: classes.page_button,
+                                    onclick: move |_| on_page_change.call(target),
+                                    "{target + 1}"
+                                }
+                            },
+                            PageItem::Ellipsis => rsx! {
+                                span { class: classes.page_ellipsis, "{texts.page_ellipsis}" }
+                            },
+                        }
+                    }
+                }
+            } else {
+                span {
+                    {
+                        indicator
+                            .replace("{current}", &(page + 1).to_string())
+                            .replace("{total}", &total_pages.to_string())
+                    }
                 }
             }
             button {
                 class: classes.pagination_button,
                 onclick: on_next,
-                disabled: page() + 1 >= total_pages,
+                disabled: !has_next_page,
                 "{texts.next_button}"
             }
+            if counted {
+                button {
+                    class: classes.pagination_button,
+                    onclick: move |_| on_page_change.call(total_pages.saturating_sub(1)),
+                    disabled: !has_next_page,
+                    "{texts.last_button}"
+                }
+                input {
+                    class: classes.page_jump,
+                    r#type: "number",
+                    min: "1",
+                    max: "{total_pages}",
+                    placeholder: texts.page_jump,
+                    "aria-label": texts.page_jump,
+                    onchange: move |e| {
+                        if let Some(target) = parse_page(&e.value(), total_pages) {
+                            on_page_change.call(target);
+                        }
+                    },
+                }
+            }
+            if !page_sizes.is_empty() {
+                label {
+                    "{texts.page_size_label}"
+                    select {
+                        class: classes.page_size_select,
+                        onchange: move |e| {
+                            if let (Ok(size), Some(on_page_size_change)) = (e.value().parse(), on_page_size_change) {
+                                on_page_size_change.call(size);
+                            }
+                        },
+                        for size in page_sizes {
+                            option {
+                                value: "{size}",
+                                selected: size == page_size,
+                                "{size}"
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(range) = range {
+                span { class: classes.pagination_range, "{range}" }
+            }
         }
     }
 }
@@ -0,0 +1,163 @@
+use crate::core::filter::{ColumnFilter, ColumnFilters, FilterInput, FilterKind};
+use crate::core::virtualize::{ColumnSlot, ColumnWindow, column_slots};
+use crate::dioxus::types::Column;
+use crate::dioxus::types::TableClasses;
+use crate::dioxus::types::TableTexts;
+use dioxus::prelude::*;
+
+/// A header row rendering one filter widget per column, according to each column's `filter`.
+///
+/// # Props
+/// - `columns`: A `Vec<Column>` whose `filter` fields select the widgets.
+/// - `filters`: The current `ColumnFilters`, shown in the widgets.
+/// - `on_filter`: An `EventHandler<(&'static str, Option<ColumnFilter>)>` called with the column ID and its new filter (`None` once it no longer restricts anything).
+/// - `classes`: A `TableClasses` struct allowing custom class names for the row, cells and inputs.
+/// - `texts`: A `TableTexts` struct providing the widget placeholders (optional).
+/// - `column_window`: The columns to render when they are virtualized; every column when `None` (optional).
+///
+/// # Behavior
+/// - `FilterKind::Text` renders a text input, `Number` and `Date` render two bound inputs, and `Select` renders a list of checkboxes.
+/// - Columns without a filter, or with a `Facet` menu shown in their header, get an empty cell.
+/// - The row holds no state; the parent applies the reported filters.
+///
+/// # Returns
+/// Returns a `Dioxus` `Element` containing a `<tr>` to render inside the table's `<thead>`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use table_rs::core::filter::{ColumnFilters, FilterKind, set_filter};
+/// use table_rs::dioxus::filters::FilterRow;
+/// use table_rs::dioxus::types::{Column, TableClasses};
+///
+/// fn App() -> Element {
+///     let columns = vec![
+///         Column { id: "age", header: "Age", filter: FilterKind::Number, ..Default::default() },
+///     ];
+///     let mut filters = use_signal(ColumnFilters::new);
+///
+///     rsx! {
+///         FilterRow {
+///             columns: columns,
+///             filters: filters(),
+///             on_filter: move |(id, filter)| set_filter(&mut filters.write(), id, filter),
+///             classes: TableClasses::default(),
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn FilterRow(
+    columns: Vec<Column>,
+    filters: ColumnFilters,
+    on_filter: EventHandler<(&'static str, Option<ColumnFilter>)>,
+    classes: TableClasses,
+    #[props(default)] texts: TableTexts,
+    #[props(default)] column_window: Option<ColumnWindow>,
+) -> Element {
+    let slots = column_slots(&columns, column_window.as_ref());
+    let cells = slots.into_iter().enumerate().map(|(position, slot)| {
+        let (col, style) = match slot {
+            ColumnSlot::Column(col, style) => (col, style),
+            ColumnSlot::Spacer(style) => {
+                return rsx! {
+                    th { key: "spacer-{position}", aria_hidden: "true", style: "{style}" }
+                };
+            }
+        };
+        let col_id = col.id;
+        let header = col.header;
+        let current = filters.get(col_id).cloned();
+        let emit = {
+            let kind = col.filter.clone();
+            let current = current.clone();
+            move |input: FilterInput| on_filter.call((col_id, kind.apply(current.as_ref(), input)))
+        };
+
+        let widget = match &col.filter {
+            FilterKind::None | FilterKind::Facet => rsx! {},
+            FilterKind::Text(_) => {
+                let value = current
+                    .as_ref()
+                    .map(|f| f.text().to_string())
+                    .unwrap_or_default();
+                rsx! {
+                    input {
+                        class: "{classes.filter_input}",
+                        r#type: "text",
+                        value: "{value}",
+                        placeholder: "{texts.filter_placeholder}",
+                        aria_label: "Filter {header}",
+                        oninput: move |e| emit(FilterInput::Text(e.value())),
+                    }
+                }
+            }
+            FilterKind::Number | FilterKind::Date => {
+                let input_type = if col.filter == FilterKind::Number {
+                    "number"
+                } else {
+                    "date"
+                };
+                let min = current
+                    .as_ref()
+                    .map(ColumnFilter::min_text)
+                    .unwrap_or_default();
+                let max = current
+                    .as_ref()
+                    .map(ColumnFilter::max_text)
+                    .unwrap_or_default();
+                let emit_max = emit.clone();
+                rsx! {
+                    input {
+                        class: "{classes.filter_input}",
+                        r#type: "{input_type}",
+                        value: "{min}",
+                        placeholder: "{texts.filter_min}",
+                        aria_label: "{header} {texts.filter_min}",
+                        oninput: move |e| emit(FilterInput::Min(e.value())),
+                    }
+                    input {
+                        class: "{classes.filter_input}",
+                        r#type: "{input_type}",
+                        value: "{max}",
+                        placeholder: "{texts.filter_max}",
+                        aria_label: "{header} {texts.filter_max}",
+                        oninput: move |e| emit_max(FilterInput::Max(e.value())),
+                    }
+                }
+            }
+            FilterKind::Select(options) => {
+                let checkboxes = options.iter().map(|&option| {
+                    let checked = current.as_ref().is_some_and(|f| f.is_selected(option));
+                    let emit = emit.clone();
+                    rsx! {
+                        label { key: "{option}",
+                            input {
+                                class: "{classes.filter_input}",
+                                r#type: "checkbox",
+                                checked: checked,
+                                onchange: move |_| emit(FilterInput::Toggle(option.to_string())),
+                            }
+                            "{option}"
+                        }
+                    }
+                });
+                rsx! {
+                    {checkboxes}
+                }
+            }
+        };
+
+        rsx! {
+            th { key: "{col_id}", class: "{classes.filter_cell}", style: "{style}",
+                {widget}
+            }
+        }
+    });
+
+    rsx! {
+        tr { class: "{classes.filter_row}", role: "row",
+            {cells}
+        }
+    }
+}
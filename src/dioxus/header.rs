@@ -1,6 +1,14 @@
+use crate::core::filter::{
+    ColumnFacets, ColumnFilter, ColumnFilters, Facet, FilterInput, FilterKind,
+};
+use crate::core::state::sort_priority;
+use crate::core::virtualize::{ColumnSlot, ColumnWindow, column_slots};
+use crate::dioxus::filters::FilterRow;
 use crate::dioxus::types::Column;
+use crate::dioxus::types::SortKey;
 use crate::dioxus::types::SortOrder;
 use crate::dioxus::types::TableClasses;
+use crate::dioxus::types::TableTexts;
 use dioxus::prelude::*;
 
 /// A table header component that renders sortable column headers for use within the `Table` component.
@@ -10,14 +18,22 @@ use dioxus::prelude::*;
 ///
 /// # Props
 /// - `columns`: A `Vec<Column>` defining the columns to display in the header. Each `Column` may be sortable and have optional styles or class overrides.
-/// - `sort_column`: A `Signal<Option<&'static str>>` indicating which column (if any) is currently being sorted.
-/// - `sort_order`: A `Signal<SortOrder>` indicating the current sort direction (`Asc` or `Desc`).
-/// - `on_sort_column`: An `EventHandler<&'static str>` triggered when a sortable header cell is clicked. The column ID is passed as the event payload.
+/// - `sort`: A `ReadSignal<Vec<SortKey>>` holding the sort stack, highest priority first; accepts a signal or a plain `Vec`.
+/// - `on_sort_column`: An `EventHandler<(&'static str, bool)>` triggered when a sortable header cell is clicked. The column ID and whether shift was held (to add a secondary sort key) are passed as the event payload.
 /// - `classes`: A `TableClasses` struct allowing custom class names for `<thead>`, `<tr>`, and `<th>` elements.
+/// - `texts`: A `TableTexts` struct providing the sort direction indicators and filter placeholders (optional).
+/// - `filters`: The current `ColumnFilters`, shown in the filter row (optional).
+/// - `on_filter`: An `EventHandler<(&'static str, Option<ColumnFilter>)>` called when a filter widget changes (optional).
+/// - `facets`: The `ColumnFacets` listed by the facet menus (optional).
+/// - `column_window`: The columns to render when they are virtualized, with spacer cells standing for the others; every column when `None` (optional).
 ///
 /// # Behavior
 /// - Sortable columns show proper `aria-sort` attributes for accessibility (`ascending`, `descending`, or `none`).
-/// - Clicking a sortable column emits an event to update sort state.
+/// - Clicking a sortable column emits an event to update sort state; shift-clicking adds or toggles a secondary key.
+/// - Sortable headers show a direction indicator (`texts.sort_ascending`, `texts.sort_descending` or `texts.sort_unsorted`) in a `<span>` styled by `classes.sort_indicator`.
+/// - When several columns are sorted, each sorted header shows its priority number in a `<span>` styled by `classes.sort_priority`.
+/// - When any column declares a `filter` widget, a `FilterRow` is rendered below the headers.
+/// - Columns using `FilterKind::Facet` get a `FacetMenu` in their header cell instead.
 /// - Each column can override default styles and classes via `Column::style` and `Column::class`.
 ///
 /// # Returns
@@ -28,7 +44,7 @@ use dioxus::prelude::*;
 /// use dioxus::prelude::*;
 /// use maplit::hashmap;
 /// use table_rs::dioxus::table::Table;
-/// use table_rs::dioxus::types::{Column, TableClasses, SortOrder};
+/// use table_rs::dioxus::types::{Column, TableClasses, SortKey, SortOrder};
 /// use table_rs::dioxus::header::TableHeader;
 ///
 ///
@@ -38,15 +54,13 @@ use dioxus::prelude::*;
 ///         Column { id: "email", header: "Email", sortable: false, ..Default::default() },
 ///     ];
 ///
-///     let sort_column = use_signal(|| Some("name"));
-///     let sort_order = use_signal(|| SortOrder::Asc);
+///     let sort = use_signal(|| vec![("name", SortOrder::Asc)] as Vec<SortKey>);
 ///
 ///     rsx! {
 ///         TableHeader {
 ///             columns: columns,
-///             sort_column: sort_column,
-///             sort_order: sort_order,
-///             on_sort_column: move |col_id| println!("Sort column changed: {}", col_id),
+///             sort: sort,
+///             on_sort_column: move |(col_id, additive)| println!("Sort column changed: {} (shift: {})", col_id, additive),
 ///             classes: TableClasses::default(),
 ///         }
 ///     }
@@ -58,29 +72,61 @@ use dioxus::prelude::*;
 #[component]
 pub fn TableHeader(
     columns: Vec<Column>,
-    sort_column: Signal<Option<&'static str>>,
-    sort_order: Signal<SortOrder>,
-    on_sort_column: EventHandler<&'static str>,
+    sort: ReadSignal<Vec<SortKey>>,
+    on_sort_column: EventHandler<(&'static str, bool)>,
     classes: TableClasses,
+    #[props(default)] texts: TableTexts,
+    #[props(default)] filters: ColumnFilters,
+    #[props(default)] on_filter: Option<EventHandler<(&'static str, Option<ColumnFilter>)>>,
+    #[props(default)] facets: ColumnFacets,
+    #[props(default)] column_window: Option<ColumnWindow>,
 ) -> Element {
-    let header_cells = columns.iter().map(|col| {
-        let col_id = col.id;
-        let is_sorted = sort_column() == Some(col_id);
-        let aria_sort = if is_sorted {
-            match sort_order() {
-                SortOrder::Asc => "ascending",
-                SortOrder::Desc => "descending",
+    let has_filters = columns.iter().any(|col| col.filter.in_filter_row());
+    let sort_keys = sort();
+    let slots = column_slots(&columns, column_window.as_ref());
+    let header_cells = slots.into_iter().enumerate().map(|(position, slot)| {
+        let (col, slot_style) = match slot {
+            ColumnSlot::Column(col, style) => (col, style),
+            ColumnSlot::Spacer(style) => {
+                return rsx! {
+                    th { key: "spacer-{position}", aria_hidden: "true", style: "{style}" }
+                };
             }
-        } else {
-            "none"
         };
+        let col_id = col.id;
+        let priority = sort_priority(&sort_keys, col_id);
+        let aria_sort = priority.map_or("none", |(_, order)| order.aria());
+        let indicator = match priority {
+            Some((_, SortOrder::Asc)) => texts.sort_ascending,
+            Some((_, SortOrder::Desc)) => texts.sort_descending,
+            None => texts.sort_unsorted,
+        };
+        let show_indicator = col.sortable && !indicator.is_empty();
+        let priority_number = priority.filter(|_| sort_keys.len() > 1).map(|(n, _)| n);
 
         let class = format!("{} {}", classes.header_cell, col.class.unwrap_or_default());
-        let style = col.style.unwrap_or_default();
+        let style = format!("{} {slot_style}", col.style.unwrap_or_default());
         let header = col.header;
+        let facet_menu = (col.filter == FilterKind::Facet).then(|| {
+            rsx! {
+                FacetMenu {
+                    column: col_id,
+                    header: header,
+                    facets: facets.get(col_id).cloned().unwrap_or_default(),
+                    filter: filters.get(col_id).cloned(),
+                    on_filter: move |change| {
+                        if let Some(on_filter) = on_filter {
+                            on_filter.call(change);
+                        }
+                    },
+                    classes: classes.clone(),
+                    texts: texts.clone(),
+                }
+            }
+        });
 
         let onclick = if col.sortable {
-            Callback::new(move |_| on_sort_column.call(col_id))
+            Callback::new(move |e: MouseEvent| on_sort_column.call((col_id, e.modifiers().shift())))
         } else {
             Callback::new(|_| {})
         };
@@ -94,6 +140,13 @@ pub fn TableHeader(
                 aria_sort: "{aria_sort}",
                 onclick: onclick,
                 "{header}"
+                if show_indicator {
+                    span { class: "{classes.sort_indicator}", aria_hidden: "true", "{indicator}" }
+                }
+                if let Some(n) = priority_number {
+                    span { class: "{classes.sort_priority}", "{n}" }
+                }
+                {facet_menu}
             }
         }
     });
@@ -103,6 +156,141 @@ pub fn TableHeader(
             tr { class: "{classes.row}", role: "row",
                 {header_cells}
             }
+            if has_filters {
+                FilterRow {
+                    columns: columns.clone(),
+                    filters: filters,
+                    on_filter: move |change| {
+                        if let Some(on_filter) = on_filter {
+                            on_filter.call(change);
+                        }
+                    },
+                    classes: classes.clone(),
+                    texts: texts.clone(),
+                    column_window: column_window.clone(),
+                }
+            }
+        }
+    }
+}
+
+/// An Excel-style dropdown menu listing the distinct values of a column with their row counts.
+///
+/// # Props
+/// - `column`: The ID of the column the menu filters.
+/// - `header`: The header text of the column, used to label the menu.
+/// - `facets`: A `Vec<Facet>` with the distinct values of the column and their row counts.
+/// - `filter`: The current `Option<ColumnFilter>` of the column.
+/// - `on_filter`: An `EventHandler<(&'static str, Option<ColumnFilter>)>` called with the column ID and its new filter.
+/// - `classes`: A `TableClasses` struct allowing custom class names for the button, menu, values and counts.
+/// - `texts`: A `TableTexts` struct providing the menu labels (optional).
+///
+/// # Behavior
+/// - A button rendered in the column header opens and closes the menu.
+/// - Each value gets a checkbox, checked while its rows are shown; unchecking it excludes the value through a `ColumnFilter::NoneOf` filter.
+/// - A leading "select all" entry shows every value again, or hides them all.
+/// - Clicks inside the menu do not sort the column.
+///
+/// # Returns
+/// Returns a `Dioxus` `Element` containing the toggle button, followed by the menu while it is open.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use table_rs::core::filter::Facet;
+/// use table_rs::dioxus::header::FacetMenu;
+/// use table_rs::dioxus::types::TableClasses;
+///
+/// fn App() -> Element {
+///     let facets = vec![
+///         Facet { value: "active".into(), count: 12 },
+///         Facet { value: "archived".into(), count: 3 },
+///     ];
+///
+///     rsx! {
+///         FacetMenu {
+///             column: "status",
+///             header: "Status",
+///             facets: facets,
+///             on_filter: move |(id, filter)| println!("{id}: {filter:?}"),
+///             classes: TableClasses::default(),
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn FacetMenu(
+    column: &'static str,
+    header: &'static str,
+    facets: Vec<Facet>,
+    filter: Option<ColumnFilter>,
+    on_filter: EventHandler<(&'static str, Option<ColumnFilter>)>,
+    classes: TableClasses,
+    #[props(default)] texts: TableTexts,
+) -> Element {
+    let mut open = use_signal(|| false);
+    let all_checked = filter.is_none();
+    let values: Vec<String> = facets.iter().map(|facet| facet.value.clone()).collect();
+
+    let options = facets.iter().map(|facet| {
+        let value = facet.value.clone();
+        let count = facet.count;
+        let excluded = filter.as_ref().is_some_and(|f| f.is_excluded(&value));
+        let label = if value.is_empty() {
+            texts.facet_blank.to_string()
+        } else {
+            value.clone()
+        };
+        let filter = filter.clone();
+
+        rsx! {
+            label { key: "{value}", class: "{classes.facet_option}",
+                input {
+                    r#type: "checkbox",
+                    checked: !excluded,
+                    onchange: move |_| {
+                        let input = FilterInput::Toggle(value.clone());
+                        on_filter.call((column, FilterKind::Facet.apply(filter.as_ref(), input)));
+                    },
+                }
+                "{label}"
+                span { class: "{classes.facet_count}", "{count}" }
+            }
+        }
+    });
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "{classes.facet_toggle}",
+            aria_haspopup: "true",
+            aria_expanded: "{open()}",
+            aria_label: "Filter {header}",
+            onclick: move |e: MouseEvent| {
+                e.stop_propagation();
+                open.toggle();
+            },
+            "{texts.facet_toggle}"
+        }
+        if open() {
+            div {
+                class: "{classes.facet_menu}",
+                role: "group",
+                aria_label: "Filter {header}",
+                onclick: move |e: MouseEvent| e.stop_propagation(),
+                label { class: "{classes.facet_option}",
+                    input {
+                        r#type: "checkbox",
+                        checked: all_checked,
+                        onchange: move |_| {
+                            let filter = ColumnFilter::NoneOf(values.clone());
+                            on_filter.call((column, (all_checked && filter.is_active()).then_some(filter)));
+                        },
+                    }
+                    "{texts.facet_select_all}"
+                }
+                {options}
+            }
         }
     }
 }
@@ -1,12 +1,24 @@
+use dioxus::core::Task;
 use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+#[cfg(target_family = "wasm")]
 use web_sys::UrlSearchParams;
+#[cfg(target_family = "wasm")]
 use web_sys::wasm_bindgen::JsValue;
 
+use crate::core::data::SharedRows;
+use crate::core::filter::{ColumnFacets, ColumnFilters, FilterKind, set_filter};
+use crate::core::model::{PipelineColumns, TableModel, total_pages};
+use crate::core::pagination::{clamp_page, refit_page, row_range};
+use crate::core::row::TableRow;
+use crate::core::sort_index::SortCache;
+use crate::core::state::{TableState, toggle_sort_key};
+use crate::core::virtualize::{ColumnWindow, DEFAULT_COLUMN_WIDTH};
 use crate::dioxus::body::TableBody;
 use crate::dioxus::controls::PaginationControls;
 use crate::dioxus::header::TableHeader;
-use crate::dioxus::types::SortOrder;
-use crate::dioxus::types::TableProps;
+use crate::dioxus::types::{SearchMode, TableProps};
 
 /// A fully featured table component with sorting, pagination, and search functionality in Dioxus.
 ///
@@ -16,19 +28,48 @@ use crate::dioxus::types::TableProps;
 ///
 /// # Props
 /// `TableProps` defines the configuration for this component:
-/// - `data`: A `Vec<HashMap<&'static str, String>>` representing row data.
+/// - `data`: A `SharedRows<T>` representing row data, where `T: TableRow` (`HashMap<&'static str, String>` by default); a `Vec<T>` converts into it, and data kept across renders is only searched, filtered and sorted again when replaced.
 /// - `columns`: A `Vec<Column>` describing each column's ID, header text, and behavior.
 /// - `page_size`: Number of rows to display per page (default: `10`).
+/// - `page_size_options`: Page sizes offered in a dropdown, hidden when empty; the selected one is
+///   passed to `on_page_size_change`.
+/// - `rich_pagination`: Adds first/last buttons, numbered pages, a "go to page" input and a
+///   "Showing 21–40 of 532" label to the pagination controls (default: `false`).
+/// - `max_sort_keys`: Maximum number of columns sorted at once with shift-click (default: `3`).
+/// - `sort_cycle`: Whether header clicks can return a column to unsorted (default: `SortCycle::Toggle`).
+/// - `collation`: How text is compared when sorting (default: `Collation::Binary`).
 /// - `loading`: When `true`, displays a loading indicator (default: `false`).
 /// - `paginate`: Enables pagination controls (default: `false`).
+/// - `virtualize`: Renders only the rows in view, in a scrolling container `viewport_height` pixels tall, plus `overscan` rows on each side; rows are `row_height` pixels tall, or measured once rendered with `measure_rows` (default: `false`).
+/// - `virtualize_columns`: Renders only the columns in view, in a scrolling container `viewport_width` pixels wide, plus `column_overscan` columns on each side; each column is assumed `min_width` pixels wide (100 when 0), and `pinned` columns are always rendered (default: `false`).
+/// - `infinite`: Shows every row instead, ended by a "loading more" row that calls `on_load_more` when it scrolls into view, as long as `has_more` is `true` (default: `false`).
 /// - `search`: Enables a search input for client-side filtering (default: `false`).
+/// - `search_mode`: Whether the search takes plain text, the query language (e.g. `status:active age:>30 -name:"test user"`) or fuzzy matching (default: `SearchMode::Plain`).
+/// - `on_search_error`: Called with a `QueryError` when the query becomes invalid; the search then keeps every row (optional).
+/// - `fuzzy_rank`: Orders `SearchMode::Fuzzy` results by score while no column is sorted (default: `false`).
+/// - `search_debounce_ms`: Milliseconds to wait after the last keystroke before searching; `0` searches on every keystroke (default: `300`).
+/// - `manual`: Leaves search, filters, sorting and pagination to the app, which passes the current page as `data` (default: `false`).
+/// - `total_rows`: Number of matching rows across all pages in `manual` mode, used to count the pages (default: `0`).
+/// - `has_next`: Whether a next page exists, for cursor pagination in `manual` mode where the number of rows is unknown (default: `None`).
+/// - `on_query_change`: Called with the `TableState` to fetch in `manual` mode, on mount and whenever the search, filters, sort or page change (optional).
+/// - `source`: A `SharedSource<T>` fetching each page asynchronously instead of reading `data`; failed fetches show an error row with a retry button (optional).
+/// - `filters`: Column filters controlled by the parent; when unset, the table keeps its own (default: `None`).
+/// - `on_filters_change`: Called with the new column filters whenever a filter widget changes (optional).
+/// - `sort`, `page`, `search_query`: Sort keys, page index and search query controlled by the parent; when unset, the table keeps its own (default: `None`).
+/// - `on_sort_change`, `on_page_change`, `on_search_change`: Called with the new sort keys, page index and search query whenever the user changes them (optional).
 /// - `texts`: Customizable text labels for UI strings (default: `TableTexts::default()`).
 /// - `classes`: Customizable CSS class names for each table part (default: `TableClasses::default()`).
 ///
 /// # Features
-/// - **Search**: Filters rows client-side using a text input; the query is persisted in the URL via `?search=`.
-/// - **Sorting**: Clickable headers allow sorting columns ascending or descending.
-/// - **Pagination**: Navigate between pages using prev/next buttons, with an indicator showing current page.
+/// - **Search**: Filters rows client-side using a text input; the query is persisted in the URL via `?search=`. `SearchMode::Query` adds field-scoped terms, quoted phrases, negation, comparisons and `OR`, while `SearchMode::Fuzzy` tolerates typos and highlights the matches.
+/// - **Column Filters**: Columns declaring a `filter` widget get a filter row under the headers; filters combine with the search.
+/// - **Facet Menus**: `FilterKind::Facet` columns get a dropdown in their header listing each distinct value with its row count, updated as the other filters change.
+/// - **Sorting**: Clickable headers allow sorting columns ascending, descending or (optionally) unsorted, with direction indicators; shift-click sorts by several columns.
+/// - **Pagination**: Navigate between pages using prev/next buttons, with an indicator showing current page;
+///   `rich_pagination` adds first/last buttons, numbered pages, a page jump and the range of rows shown.
+/// - **Controlled Mode**: The sort, page, search query and filters can be owned by the parent, which is told about every change.
+/// - **Manual Mode**: For server-side data, the app fetches each page on `on_query_change` and the table renders `data` as is.
+/// - **Async Data Sources**: A `DataSource` fetches each page, with the loading row shown meanwhile and an error row on failure.
 /// - **Custom Classes**: All elements are styled via `TableClasses` for full customization.
 /// - **Text Overrides**: All UI strings (e.g., empty state, loading, buttons) can be customized using `TableTexts`.
 ///
@@ -39,15 +80,19 @@ use crate::dioxus::types::TableProps;
 /// ```rust
 /// use dioxus::prelude::*;
 /// use maplit::hashmap;
+/// use table_rs::core::data::SharedRows;
 /// use table_rs::dioxus::table::Table;
 /// use table_rs::dioxus::types::Column;
 ///
 ///
 /// fn App() -> Element {
-///     let data = vec![
-///         hashmap! { "name" => "ferris".to_string(), "email" => "ferris@opensass.org".to_string() },
-///         hashmap! { "name" => "ferros".to_string(), "email" => "ferros@opensass.org".to_string() },
-///     ];
+///     // Kept across renders, so that the table only processes the rows when they change.
+///     let data = use_signal(|| {
+///         SharedRows::from(vec![
+///             hashmap! { "name" => "ferris".to_string(), "email" => "ferris@opensass.org".to_string() },
+///             hashmap! { "name" => "ferros".to_string(), "email" => "ferros@opensass.org".to_string() },
+///         ])
+///     });
 ///
 ///     let columns = vec![
 ///         Column { id: "name", header: "Name", sortable: true, ..Default::default() },
@@ -56,7 +101,7 @@ use crate::dioxus::types::TableProps;
 ///
 ///     rsx! {
 ///         Table {
-///             data: data,
+///             data: data(),
 ///             columns: columns,
 ///             paginate: true,
 ///             search: true,
@@ -65,25 +110,115 @@ use crate::dioxus::types::TableProps;
 /// }
 /// ```
 ///
+/// Rows can also be typed structs deriving `TableRow` (requires the `derive` feature):
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # mod example {
+/// use dioxus::prelude::*;
+/// use table_rs::TableRow;
+/// use table_rs::core::data::SharedRows;
+/// use table_rs::core::row::TableRow as _;
+/// use table_rs::dioxus::table::Table;
+/// use table_rs::dioxus::types::Column;
+///
+/// #[derive(Clone, PartialEq, TableRow)]
+/// struct User {
+///     #[table(sortable)]
+///     name: String,
+///     #[table(header = "Email", sortable)]
+///     email: String,
+///     age: u32,
+/// }
+///
+/// fn App() -> Element {
+///     let data = use_signal(|| {
+///         SharedRows::from(vec![
+///             User { name: "Ferris".into(), email: "ferris@opensass.org".into(), age: 12 },
+///             User { name: "Crab".into(), email: "crab@opensass.org".into(), age: 7 },
+///         ])
+///     });
+///     let columns: Vec<Column> = User::columns();
+///
+///     rsx! {
+///         Table { data: data(), columns: columns, paginate: true }
+///     }
+/// }
+/// # }
+/// ```
+///
 /// # See Also
 /// - [MDN `<table>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/table)
 #[component]
-pub fn Table(props: TableProps) -> Element {
+pub fn Table<T: TableRow + Clone + PartialEq + 'static>(props: TableProps<T>) -> Element {
     let TableProps {
         data,
         columns,
         page_size,
+        page_size_options,
+        on_page_size_change,
+        rich_pagination,
+        max_sort_keys,
+        sort_cycle,
+        collation,
         loading,
         paginate,
+        infinite,
+        has_more,
+        on_load_more,
+        virtualize,
+        row_height,
+        measure_rows,
+        viewport_height,
+        overscan,
+        virtualize_columns,
+        viewport_width,
+        column_overscan,
         search,
+        search_mode,
+        on_search_error,
+        fuzzy_rank,
+        search_debounce_ms,
+        manual,
+        total_rows,
+        has_next,
+        on_query_change,
+        source,
         texts,
         classes,
+        filters,
+        on_filters_change,
+        sort,
+        on_sort_change,
+        page,
+        on_page_change,
+        search_query,
+        on_search_change,
     } = props;
 
-    let mut page = use_signal(|| 0_usize);
-    let mut sort_column = use_signal(|| None::<&'static str>);
-    let mut sort_order = use_signal(SortOrder::default);
-    let mut search_query = use_signal(String::new);
+    let mut page_state = use_signal(|| 0_usize);
+    let page = page.unwrap_or_else(|| page_state.cloned());
+    let mut page_size_state = use_signal(|| None::<usize>);
+    let page_size = page_size_state().unwrap_or(page_size);
+    let mut sort_state = use_signal(Vec::new);
+    let sort = sort.unwrap_or_else(|| sort_state.cloned());
+    let mut filter_state = use_signal(ColumnFilters::new);
+    let filters = filters.unwrap_or_else(|| filter_state.cloned());
+    let mut query_state = use_signal(String::new);
+    let query = search_query.clone().unwrap_or_else(|| query_state.cloned());
+    let mut search_input = use_signal(String::new);
+    let mut pending_search = use_signal(|| None::<Task>);
+    use_effect(use_reactive!(|search_query| {
+        if let Some(query) = search_query {
+            search_input.set(query);
+        }
+    }));
+
+    let mut set_page = move |page: usize| {
+        page_state.set(page);
+        if let Some(on_page_change) = on_page_change {
+            on_page_change.call(page);
+        }
+    };
 
     #[cfg(target_family = "wasm")]
     use_effect(move || {
@@ -92,7 +227,8 @@ pub fn Table(props: TableProps) -> Element {
         let search = location.search().unwrap_or_default();
         let params = UrlSearchParams::new_with_str(&search).unwrap();
         if let Some(search_val) = params.get("search") {
-            search_query.set(search_val);
+            search_input.set(search_val.clone());
+            query_state.set(search_val);
         }
     });
 
@@ -112,57 +248,198 @@ pub fn Table(props: TableProps) -> Element {
             .unwrap();
     };
 
-    let filtered_rows = {
-        let mut rows = data.clone();
-        if !search_query().is_empty() {
-            rows.retain(|row| {
-                columns.iter().any(|col| {
-                    row.get(col.id)
-                        .map(|v| v.to_lowercase().contains(&search_query().to_lowercase()))
-                        .unwrap_or(false)
-                })
-            });
+    let state = TableState {
+        query: query.clone(),
+        search_mode,
+        fuzzy_rank,
+        filters: filters.clone(),
+        sort: sort.clone(),
+        max_sort_keys,
+        sort_cycle,
+        // Infinite tables show every row.
+        page: if infinite { 0 } else { page },
+        page_size: if infinite { 0 } else { page_size },
+    };
+    let mut fetched = use_signal(|| None::<(SharedRows<T>, usize, Option<bool>)>);
+    let mut fetching = use_signal(|| false);
+    let mut fetch_error = use_signal(|| None::<String>);
+    let mut retries = use_signal(|| 0_u32);
+    let mut pending_fetch = use_signal(|| None::<Task>);
+    let (fetch_state, fetch_source) = (state.clone(), source.clone());
+    use_effect(use_reactive!(|(fetch_state, fetch_source)| {
+        // Reading `retries` lets the retry button fetch the same page again.
+        retries();
+        // Cancelling the task of a superseded request drops its response.
+        if let Some(task) = pending_fetch.take() {
+            task.cancel();
         }
+        let Some(source) = fetch_source else {
+            fetching.set(false);
+            return;
+        };
+        let request = source.fetch(fetch_state);
+        fetching.set(true);
+        pending_fetch.set(Some(spawn(async move {
+            match request.await {
+                Ok(page) => {
+                    fetched.set(Some((page.rows.into(), page.total_rows, page.has_next)));
+                    fetch_error.set(None);
+                }
+                Err(error) => fetch_error.set(Some(error)),
+            }
+            fetching.set(false);
+        })));
+    }));
 
-        if let Some(col_id) = sort_column() {
-            if let Some(col) = columns.iter().find(|c| c.id == col_id) {
-                rows.sort_by(|a, b| {
-                    let val = "".to_string();
-                    let a_val = a.get(col.id).unwrap_or(&val);
-                    let b_val = b.get(col.id).unwrap_or(&val);
-                    match sort_order() {
-                        SortOrder::Asc => a_val.cmp(b_val),
-                        SortOrder::Desc => b_val.cmp(a_val),
-                    }
-                });
+    let fetched_page = fetched.cloned();
+    let (rows, paged, total_rows, has_next) = match (&source, &fetched_page) {
+        (Some(_), Some((rows, total_rows, has_next))) => {
+            (rows.clone(), true, *total_rows, *has_next)
+        }
+        (Some(_), None) => (SharedRows::default(), true, 0, None),
+        (None, _) => (data.clone(), manual, total_rows, has_next),
+    };
+    let loading = loading || fetching();
+    // Rows are searched, filtered and sorted again only once they or those settings change, not
+    // when paging or scrolling. Pages of manual and async tables are shown as given.
+    let sort_cache = use_hook(|| Rc::new(RefCell::new((SharedRows::default(), SortCache::new()))));
+    // Columns only count through what the pipeline reads of them, so that columns built on every
+    // render with new callbacks do not run it again.
+    let (pipeline_rows, pipeline_columns) = (rows.clone(), PipelineColumns::new(columns.clone()));
+    let facet_columns: Vec<&'static str> = columns
+        .iter()
+        .filter(|col| col.filter == FilterKind::Facet)
+        .map(|col| col.id)
+        .collect();
+    let pipeline_state = TableState {
+        page: 0,
+        page_size: 0,
+        ..state.clone()
+    };
+    let pipeline = use_memo(use_reactive!(|(
+        pipeline_rows,
+        pipeline_columns,
+        facet_columns,
+        collation,
+        paged,
+        pipeline_state,
+    )| {
+        let model =
+            TableModel::new(&pipeline_rows, pipeline_columns.columns()).with_collation(collation);
+        let facets: ColumnFacets = facet_columns
+            .iter()
+            .map(|&column| (column, model.facets(column, &pipeline_state)))
+            .collect();
+        if paged {
+            let search_error = model.manual_view(&pipeline_state, 0).search_error;
+            (None, search_error, facets)
+        } else {
+            let mut sort_cache = sort_cache.borrow_mut();
+            let (sorted, cache) = &mut *sort_cache;
+            // Sort indexes stay valid when rows were only appended, e.g. by infinite scroll.
+            if !pipeline_rows.extends(sorted) {
+                cache.clear();
             }
+            *sorted = pipeline_rows.clone();
+            let order = model.order_cached(&pipeline_state, cache);
+            (
+                Some(SharedRows::from(order.indices)),
+                order.search_error,
+                facets,
+            )
         }
+    }));
+    let (order, search_error, facets) = pipeline.cloned();
+    let view_total_rows = order.as_ref().map_or(total_rows, |order| order.len());
+    let total_pages = total_pages(view_total_rows, state.page_size);
+    let view_page = match order {
+        Some(_) => clamp_page(state.page, total_pages),
+        None => state.page,
+    };
+    let shown_page_size = state.page_size;
+    let indices = use_memo(use_reactive!(|(order, view_page, shown_page_size)| {
+        let order = order?;
+        Some(match row_range(view_page, shown_page_size, order.len()) {
+            Some((1, last)) if last == order.len() => order,
+            Some((first, last)) => order[first - 1..last].iter().copied().collect(),
+            None => SharedRows::default(),
+        })
+    }));
+    let table_state = state.clone();
+    let manual = manual && source.is_none();
+    use_effect(use_reactive!(|table_state| {
+        if let (true, Some(on_query_change)) = (manual, on_query_change) {
+            on_query_change.call(table_state);
+        }
+    }));
+    use_effect(use_reactive!(|search_error| {
+        if let (Some(error), Some(on_search_error)) = (search_error, on_search_error) {
+            on_search_error.call(error);
+        }
+    }));
+    // Once the rows are known, fall back to the last page when they no longer reach the current
+    // one, e.g. after `data` shrank. Cursor-based pages have no known last page.
+    let settled = !loading && (source.is_none() || fetched_page.is_some());
+    let shown_page = match has_next {
+        None if settled => view_page,
+        _ => page,
+    };
+    use_effect(use_reactive!(|(page, shown_page)| {
+        if shown_page != page {
+            set_page(shown_page);
+        }
+    }));
+    let page = shown_page;
+    let highlight = match search_mode {
+        SearchMode::Fuzzy => query,
+        _ => String::new(),
+    };
 
-        rows
+    let on_sort_column = {
+        let sort = sort.clone();
+        move |(id, additive): (&'static str, bool)| {
+            let mut keys = sort.clone();
+            toggle_sort_key(&mut keys, id, additive, max_sort_keys, sort_cycle);
+            sort_state.set(keys.clone());
+            if let Some(on_sort_change) = on_sort_change {
+                on_sort_change.call(keys);
+            }
+        }
     };
 
-    let total_pages = (filtered_rows.len() as f64 / page_size as f64).ceil() as usize;
-    let start = page() * page_size;
-    let end = ((page() + 1) * page_size).min(filtered_rows.len());
-    let page_rows = &filtered_rows[start..end];
+    let on_filter = {
+        let filters = filters.clone();
+        move |(id, filter)| {
+            let mut next = filters.clone();
+            set_filter(&mut next, id, filter);
+            filter_state.set(next.clone());
+            if let Some(on_filters_change) = on_filters_change {
+                on_filters_change.call(next);
+            }
+            set_page(0);
+        }
+    };
 
-    let on_sort_column = move |id: &'static str| {
-        if Some(id) == sort_column() {
-            sort_order.set(match sort_order() {
-                SortOrder::Asc => SortOrder::Desc,
-                SortOrder::Desc => SortOrder::Asc,
-            });
-        } else {
-            sort_column.set(Some(id));
-            sort_order.set(SortOrder::Asc);
+    let on_page_size = move |size: usize| {
+        set_page(refit_page(page, page_size, size));
+        page_size_state.set(Some(size));
+        if let Some(on_page_size_change) = on_page_size_change {
+            on_page_size_change.call(size);
         }
     };
 
-    let pagination_controls = if paginate {
+    let pagination_controls = if paginate && !infinite {
         rsx! {
             PaginationControls {
                 page: page,
+                on_page_change: set_page,
                 total_pages: total_pages,
+                has_next: has_next,
+                rich: rich_pagination,
+                page_size: page_size,
+                page_sizes: page_size_options.clone(),
+                on_page_size_change: on_page_size,
+                total_rows: has_next.is_none().then_some(view_total_rows),
                 classes: classes.clone(),
                 texts: texts.clone(),
             }
@@ -171,6 +448,66 @@ pub fn Table(props: TableProps) -> Element {
         rsx! {}
     };
 
+    let mut scroll_top = use_signal(|| 0.0);
+    let mut scroll_left = use_signal(|| 0.0);
+    let column_window = virtualize_columns.then(|| {
+        let widths: Vec<_> = columns
+            .iter()
+            .map(|col| match col.min_width {
+                0 => (DEFAULT_COLUMN_WIDTH, col.pinned),
+                width => (f64::from(width), col.pinned),
+            })
+            .collect();
+        ColumnWindow::new(&widths, scroll_left(), viewport_width, column_overscan)
+    });
+    let mut viewport_style = String::new();
+    if virtualize {
+        viewport_style += &format!("height: {viewport_height}px; overflow-y: auto;");
+    }
+    if virtualize_columns {
+        viewport_style += &format!(" max-width: {viewport_width}px; overflow-x: auto;");
+    }
+    let table = rsx! {
+        table {
+            class: "{classes.table}",
+            TableHeader {
+                columns: columns.clone(),
+                sort: sort,
+                on_sort_column: on_sort_column,
+                classes: classes.clone(),
+                texts: texts.clone(),
+                filters: filters,
+                on_filter: on_filter,
+                facets: facets,
+                column_window: column_window.clone(),
+            }
+            TableBody::<T> {
+                columns: columns.clone(),
+                rows: rows,
+                indices: indices(),
+                loading: loading,
+                classes: classes.clone(),
+                texts: texts.clone(),
+                highlight: highlight,
+                error: fetch_error(),
+                on_retry: move |_| retries += 1,
+                has_more: infinite && has_more,
+                on_load_more: move |_| {
+                    if let Some(on_load_more) = on_load_more {
+                        on_load_more.call(());
+                    }
+                },
+                virtualize: virtualize,
+                row_height: row_height,
+                measure_rows: measure_rows,
+                overscan: overscan,
+                scroll_top: scroll_top(),
+                viewport_height: viewport_height,
+                column_window: column_window,
+            }
+        }
+    };
+
     rsx! {
         div {
             class: "{classes.container}",
@@ -178,33 +515,54 @@ pub fn Table(props: TableProps) -> Element {
                 input {
                     class: "{classes.search_input}",
                     r#type: "text",
-                    value: "{search_query()}",
+                    value: "{search_input()}",
                     placeholder: "{texts.search_placeholder}",
                     oninput: move |e| {
                         let val = e.value();
-                        search_query.set(val.clone());
-                        page.set(0);
-                        #[cfg(target_family = "wasm")]
-                        update_search_param(&val);
+                        search_input.set(val.clone());
+                        if let Some(task) = pending_search.take() {
+                            task.cancel();
+                        }
+                        let mut apply = move |val: String| {
+                            query_state.set(val.clone());
+                            if let Some(on_search_change) = on_search_change {
+                                on_search_change.call(val.clone());
+                            }
+                            set_page(0);
+                            #[cfg(target_family = "wasm")]
+                            update_search_param(&val);
+                        };
+                        if search_debounce_ms == 0 {
+                            apply(val);
+                            return;
+                        }
+                        pending_search.set(Some(spawn(async move {
+                            // Browsers have no Tokio runtime, while desktop and server renderers
+                            // run on one.
+                            #[cfg(target_family = "wasm")]
+                            gloo_timers::future::TimeoutFuture::new(search_debounce_ms).await;
+                            #[cfg(not(target_family = "wasm"))]
+                            tokio::time::sleep(std::time::Duration::from_millis(
+                                search_debounce_ms.into(),
+                            ))
+                            .await;
+                            apply(val);
+                        })));
                     }
                 }
             }
-            table {
-                class: "{classes.table}",
-                TableHeader {
-                    columns: columns.clone(),
-                    sort_column: sort_column,
-                    sort_order: sort_order,
-                    on_sort_column: on_sort_column,
-                    classes: classes.clone(),
-                }
-                TableBody {
-                    columns: columns.clone(),
-                    rows: page_rows.to_vec(),
-                    loading: loading,
-                    classes: classes.clone(),
-                    texts: texts.clone(),
+            if virtualize || virtualize_columns {
+                div {
+                    class: "{classes.viewport}",
+                    style: "{viewport_style.trim()}",
+                    onscroll: move |e| {
+                        scroll_top.set(e.scroll_top());
+                        scroll_left.set(e.scroll_left());
+                    },
+                    {table}
                 }
+            } else {
+                {table}
             }
             {pagination_controls}
         }
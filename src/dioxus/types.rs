@@ -1,8 +1,22 @@
+use crate::core::compare::{Comparator, SortKeyFn};
+use crate::core::data::SharedRows;
+use crate::core::filter::{ColumnFilters, FilterKind};
+use crate::core::model::ColumnSpec;
+use crate::core::query::QueryError;
+use crate::core::row::{ColumnMeta, TableRow};
+use crate::core::source::SharedSource;
+use crate::core::state::{DEFAULT_MAX_SORT_KEYS, TableState};
+use crate::core::value::{CellKind, NullOrder};
+use crate::core::virtualize::DEFAULT_ROW_HEIGHT;
 use dioxus::prelude::*;
 use std::collections::HashMap;
 
+pub use crate::core::compare::Collation;
+pub use crate::core::normalize::Normalizer;
+pub use crate::core::state::{SearchMode, SortCycle, SortKey, SortOrder};
+
 /// Represents a column definition for the table.
-#[derive(PartialEq, Props, Clone, Default)]
+#[derive(PartialEq, Props, Clone)]
 pub struct Column {
     /// Unique identifier for the column.
     pub id: &'static str,
@@ -29,6 +43,117 @@ pub struct Column {
     /// Optional CSS classes for the column header.
     #[props(default)]
     pub class: Option<&'static str>,
+
+    /// How cell values are interpreted when sorting (numbers, dates, booleans...).
+    #[props(default)]
+    pub kind: CellKind,
+
+    /// Where empty cells go when sorting, regardless of the sort direction.
+    #[props(default)]
+    pub nulls: NullOrder,
+
+    /// Maps cell values to the keys they are sorted by, e.g. to rank severity levels.
+    ///
+    /// Compared by identity: create the columns once (e.g. in a `use_hook`), or the table sorts its
+    /// rows again on every render.
+    #[props(default)]
+    pub sort_key: Option<SortKeyFn>,
+
+    /// Custom ordering used instead of the built-in comparison when sorting, e.g. for versions.
+    ///
+    /// Compared by identity: create the columns once (e.g. in a `use_hook`), or the table sorts its
+    /// rows again on every render.
+    #[props(default)]
+    pub comparator: Option<Comparator>,
+
+    /// Collation used to compare text when sorting; defaults to the table's `collation`.
+    #[props(default)]
+    pub collation: Option<Collation>,
+
+    /// Filter widget rendered for the column in the header's filter row.
+    #[props(default)]
+    pub filter: FilterKind,
+
+    /// Whether the global search looks at the column; turn off for ids or timestamps.
+    #[props(default = true)]
+    pub searchable: bool,
+
+    /// How the plain search normalizes the column's cells and the query, e.g. to ignore accents.
+    #[props(default)]
+    pub normalizer: Normalizer,
+
+    /// Whether the column stays rendered and stuck to the left edge when the table's columns are
+    /// virtualized; pinned columns come first.
+    #[props(default = false)]
+    pub pinned: bool,
+}
+
+impl Default for Column {
+    fn default() -> Self {
+        Self {
+            id: "",
+            header: "",
+            sortable: false,
+            cell: None,
+            min_width: 0,
+            style: None,
+            class: None,
+            kind: CellKind::Auto,
+            nulls: NullOrder::Last,
+            sort_key: None,
+            comparator: None,
+            collation: None,
+            filter: FilterKind::None,
+            searchable: true,
+            normalizer: Normalizer::CaseFold,
+            pinned: false,
+        }
+    }
+}
+
+impl ColumnSpec for Column {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn kind(&self) -> CellKind {
+        self.kind
+    }
+
+    fn nulls(&self) -> NullOrder {
+        self.nulls
+    }
+
+    fn sort_key(&self) -> Option<&SortKeyFn> {
+        self.sort_key.as_ref()
+    }
+
+    fn comparator(&self) -> Option<&Comparator> {
+        self.comparator.as_ref()
+    }
+
+    fn collation(&self) -> Option<Collation> {
+        self.collation
+    }
+
+    fn searchable(&self) -> bool {
+        self.searchable
+    }
+
+    fn normalizer(&self) -> Normalizer {
+        self.normalizer
+    }
+}
+
+impl From<ColumnMeta> for Column {
+    fn from(meta: ColumnMeta) -> Self {
+        Self {
+            id: meta.id,
+            header: meta.header,
+            sortable: meta.sortable,
+            ..Default::default()
+        }
+    }
 }
 
 /// Text labels for table UI elements.
@@ -38,10 +163,22 @@ pub struct TableTexts {
     #[props(default = "Loading...")]
     pub loading: &'static str,
 
+    /// Text of the row shown at the end of an infinite table while more rows are loading.
+    #[props(default = "Loading more...")]
+    pub loading_more: &'static str,
+
     /// Text shown when no data is available.
     #[props(default = "No results found")]
     pub empty: &'static str,
 
+    /// Text shown when fetching data failed.
+    #[props(default = "Failed to load data.")]
+    pub error: &'static str,
+
+    /// Label of the button fetching the data again after a failure.
+    #[props(default = "Retry")]
+    pub retry: &'static str,
+
     /// Placeholder text for the search input.
     #[props(default = "Search...")]
     pub search_placeholder: &'static str,
@@ -54,20 +191,103 @@ pub struct TableTexts {
     #[props(default = "Next")]
     pub next_button: &'static str,
 
+    /// Label for the first page button.
+    #[props(default = "First")]
+    pub first_button: &'static str,
+
+    /// Label for the last page button.
+    #[props(default = "Last")]
+    pub last_button: &'static str,
+
+    /// Text standing for skipped page numbers.
+    #[props(default = "…")]
+    pub page_ellipsis: &'static str,
+
+    /// Label and placeholder of the "go to page" input.
+    #[props(default = "Go to page")]
+    pub page_jump: &'static str,
+
+    /// Label of the page size dropdown.
+    #[props(default = "Rows per page")]
+    pub page_size_label: &'static str,
+
+    /// Range of rows shown, with placeholders `{start}`, `{end}` and `{total}`.
+    #[props(default = "Showing {start}–{end} of {total}")]
+    pub range_indicator: &'static str,
+
     /// Page indicator text with placeholders `{current}` and `{total}`.
     #[props(default = "Page {current} of {total}")]
     pub page_indicator: &'static str,
+
+    /// Page indicator text of cursor pagination, where the total is unknown, with `{current}`.
+    #[props(default = "Page {current}")]
+    pub cursor_indicator: &'static str,
+
+    /// Indicator shown in the header of a column sorted in ascending order.
+    #[props(default = "▲")]
+    pub sort_ascending: &'static str,
+
+    /// Indicator shown in the header of a column sorted in descending order.
+    #[props(default = "▼")]
+    pub sort_descending: &'static str,
+
+    /// Indicator shown in the header of a sortable column that is not sorted; hidden when empty.
+    #[props(default = "")]
+    pub sort_unsorted: &'static str,
+
+    /// Placeholder for the text column filters.
+    #[props(default = "Filter...")]
+    pub filter_placeholder: &'static str,
+
+    /// Placeholder for the lower bound of number column filters.
+    #[props(default = "Min")]
+    pub filter_min: &'static str,
+
+    /// Placeholder for the upper bound of number column filters.
+    #[props(default = "Max")]
+    pub filter_max: &'static str,
+
+    /// Label of the button opening a column's facet menu.
+    #[props(default = "▾")]
+    pub facet_toggle: &'static str,
+
+    /// Label of the facet menu entry checking or unchecking every value.
+    #[props(default = "(Select all)")]
+    pub facet_select_all: &'static str,
+
+    /// Label of the facet value standing for empty cells.
+    #[props(default = "(Blanks)")]
+    pub facet_blank: &'static str,
 }
 
 impl Default for TableTexts {
     fn default() -> Self {
         Self {
             loading: "Loading...",
+            loading_more: "Loading more...",
             empty: "No results found",
+            error: "Failed to load data.",
+            retry: "Retry",
             search_placeholder: "Search...",
             previous_button: "Previous",
             next_button: "Next",
+            first_button: "First",
+            last_button: "Last",
+            page_ellipsis: "…",
+            page_jump: "Go to page",
+            page_size_label: "Rows per page",
+            range_indicator: "Showing {start}–{end} of {total}",
             page_indicator: "Page {current} of {total}",
+            cursor_indicator: "Page {current}",
+            sort_ascending: "▲",
+            sort_descending: "▼",
+            sort_unsorted: "",
+            filter_placeholder: "Filter...",
+            filter_min: "Min",
+            filter_max: "Max",
+            facet_toggle: "▾",
+            facet_select_all: "(Select all)",
+            facet_blank: "(Blanks)",
         }
     }
 }
@@ -108,8 +328,68 @@ pub struct TableClasses {
     /// Class for the row shown when no data is found.
     pub empty_row: &'static str,
 
+    /// Class for the row shown when fetching data failed.
+    pub error_row: &'static str,
+
+    /// Class for the retry button of the error row.
+    pub retry_button: &'static str,
+
     /// Class for pagination buttons.
     pub pagination_button: &'static str,
+
+    /// Class for the numbered page buttons.
+    pub page_button: &'static str,
+
+    /// Class for the button of the current page, in addition to `page_button`.
+    pub page_button_active: &'static str,
+
+    /// Class for the ellipses between page numbers.
+    pub page_ellipsis: &'static str,
+
+    /// Class for the "go to page" input.
+    pub page_jump: &'static str,
+
+    /// Class for the page size dropdown.
+    pub page_size_select: &'static str,
+
+    /// Class for the "Showing 21–40 of 532" range label.
+    pub pagination_range: &'static str,
+
+    /// Class for the sort priority number shown in headers when sorting by several columns.
+    pub sort_priority: &'static str,
+
+    /// Class for the sort direction indicator shown in sortable headers.
+    pub sort_indicator: &'static str,
+
+    /// Class for the header row holding the column filters.
+    pub filter_row: &'static str,
+
+    /// Class for the cells of the filter row.
+    pub filter_cell: &'static str,
+
+    /// Class for the inputs of the column filters.
+    pub filter_input: &'static str,
+
+    /// Class for the button opening a column's facet menu.
+    pub facet_toggle: &'static str,
+
+    /// Class for the dropdown listing a column's facets.
+    pub facet_menu: &'static str,
+
+    /// Class for each value of a facet menu.
+    pub facet_option: &'static str,
+
+    /// Class for the row count shown next to each facet value.
+    pub facet_count: &'static str,
+
+    /// Class for the `<mark>` highlighting fuzzy search matches in cells.
+    pub highlight: &'static str,
+
+    /// Class for the scrolling container of a virtualized table.
+    pub viewport: &'static str,
+
+    /// Class for the rows standing for the rows of a virtualized body that are not rendered.
+    pub spacer_row: &'static str,
 }
 
 impl Default for TableClasses {
@@ -126,17 +406,44 @@ impl Default for TableClasses {
             row: "tr",
             loading_row: "loading-row",
             empty_row: "empty-row",
+            error_row: "error-row",
+            retry_button: "retry-button",
             pagination_button: "pagination-button",
+            page_button: "pagination-page",
+            page_button_active: "pagination-page-active",
+            page_ellipsis: "pagination-ellipsis",
+            page_jump: "pagination-jump",
+            page_size_select: "pagination-size",
+            pagination_range: "pagination-range",
+            sort_priority: "sort-priority",
+            sort_indicator: "sort-indicator",
+            filter_row: "filter-row",
+            filter_cell: "filter-cell",
+            filter_input: "filter-input",
+            facet_toggle: "facet-toggle",
+            facet_menu: "facet-menu",
+            facet_option: "facet-option",
+            facet_count: "facet-count",
+            highlight: "search-highlight",
+            viewport: "table-viewport",
+            spacer_row: "spacer-row",
         }
     }
 }
 
 /// Main props for the table component.
 #[derive(PartialEq, Props, Clone)]
-pub struct TableProps {
-    /// Data rows, where each row is a key-value map.
-    #[props(default)]
-    pub data: Vec<HashMap<&'static str, String>>,
+pub struct TableProps<T = HashMap<&'static str, String>>
+where
+    T: TableRow + Clone + PartialEq + 'static,
+{
+    /// Data rows; key-value maps by default, or any type implementing `TableRow`.
+    ///
+    /// Accepts a `Vec<T>`, or a [`SharedRows<T>`] kept across renders (e.g. in a signal): data is
+    /// compared by identity, and the rows are only searched, filtered and sorted again once it is
+    /// replaced.
+    #[props(default, into)]
+    pub data: SharedRows<T>,
 
     /// Definitions of columns to display.
     #[props(default)]
@@ -146,6 +453,32 @@ pub struct TableProps {
     #[props(default = 10)]
     pub page_size: usize,
 
+    /// Page sizes offered in a dropdown of the pagination controls; hidden when empty.
+    #[props(default)]
+    pub page_size_options: Vec<usize>,
+
+    /// Called with the page size the user selects.
+    #[props(default)]
+    pub on_page_size_change: Option<EventHandler<usize>>,
+
+    /// Whether the pagination controls add first/last buttons, numbered pages, a "go to page"
+    /// input and the range of rows shown.
+    #[props(default = false)]
+    pub rich_pagination: bool,
+
+    /// Maximum number of columns sorted at once with shift-click (default is 3).
+    #[props(default = DEFAULT_MAX_SORT_KEYS)]
+    pub max_sort_keys: usize,
+
+    /// How repeated header clicks cycle through sort states; `SortCycle::TriState` adds an
+    /// unsorted state after descending.
+    #[props(default)]
+    pub sort_cycle: SortCycle,
+
+    /// Collation used to compare text when sorting columns that do not set their own.
+    #[props(default)]
+    pub collation: Collation,
+
     /// Indicates whether the table is loading.
     #[props(default)]
     pub loading: bool,
@@ -154,10 +487,132 @@ pub struct TableProps {
     #[props(default = false)]
     pub paginate: bool,
 
+    /// Whether to show every row and ask for more through `on_load_more` when the end of the
+    /// table scrolls into view, instead of paginating.
+    #[props(default = false)]
+    pub infinite: bool,
+
+    /// Whether more rows can be loaded in `infinite` mode; set to `false` at the end of the data.
+    #[props(default = true)]
+    pub has_more: bool,
+
+    /// Called when the end of an `infinite` table scrolls into view.
+    #[props(default)]
+    pub on_load_more: Option<EventHandler<()>>,
+
+    /// Whether to render only the rows in view, in a scrolling container `viewport_height` pixels
+    /// tall, instead of every row.
+    #[props(default = false)]
+    pub virtualize: bool,
+
+    /// Height of each row of a virtualized table, in pixels, or their estimate with
+    /// `measure_rows`.
+    #[props(default = DEFAULT_ROW_HEIGHT)]
+    pub row_height: f64,
+
+    /// Whether rows of a virtualized table have variable heights, measured once rendered.
+    #[props(default = false)]
+    pub measure_rows: bool,
+
+    /// Height of the scrolling container of a virtualized table, in pixels.
+    #[props(default = 480.0)]
+    pub viewport_height: f64,
+
+    /// Number of rows rendered above and below the viewport of a virtualized table.
+    #[props(default = 5)]
+    pub overscan: usize,
+
+    /// Whether to render only the columns in view, in a scrolling container `viewport_width`
+    /// pixels wide, instead of every column; each column is assumed `min_width` pixels wide.
+    #[props(default = false)]
+    pub virtualize_columns: bool,
+
+    /// Width of the scrolling container of a table with virtualized columns, in pixels.
+    #[props(default = 960.0)]
+    pub viewport_width: f64,
+
+    /// Number of columns rendered left and right of the viewport when columns are virtualized.
+    #[props(default = 2)]
+    pub column_overscan: usize,
+
     /// Enables the search input field.
     #[props(default = false)]
     pub search: bool,
 
+    /// Whether the search input takes plain text, the query language or fuzzy matching.
+    #[props(default)]
+    pub search_mode: SearchMode,
+
+    /// Called when the search query becomes invalid in `SearchMode::Query`.
+    #[props(default)]
+    pub on_search_error: Option<EventHandler<QueryError>>,
+
+    /// Whether `SearchMode::Fuzzy` results are ordered by score while no column is sorted.
+    #[props(default = false)]
+    pub fuzzy_rank: bool,
+
+    /// Milliseconds the search waits after the last keystroke before filtering; `0` filters on
+    /// every keystroke.
+    #[props(default = 300)]
+    pub search_debounce_ms: u32,
+
+    /// Whether the data is searched, filtered, sorted and paginated by the app, e.g. on a server:
+    /// `data` is then rendered as the current page, as is.
+    #[props(default = false)]
+    pub manual: bool,
+
+    /// Number of rows matching the search and filters across all pages, in `manual` mode.
+    #[props(default = 0)]
+    pub total_rows: usize,
+
+    /// Whether a next page exists, for cursor pagination in `manual` mode where the number of
+    /// rows is unknown; when set, `total_rows` is ignored and the page indicator has no total.
+    #[props(default)]
+    pub has_next: Option<bool>,
+
+    /// Called with the search, filters, sort and page to fetch, in `manual` mode; also
+    /// called once on mount.
+    #[props(default)]
+    pub on_query_change: Option<EventHandler<TableState>>,
+
+    /// Source fetching the rows page by page whenever the search, filters, sort or page change;
+    /// when set, `data`, `manual` and `total_rows` are ignored.
+    #[props(default)]
+    pub source: Option<SharedSource<T>>,
+
+    /// Column filters to apply; when set, the table is controlled and only renders these filters.
+    #[props(default)]
+    pub filters: Option<ColumnFilters>,
+
+    /// Called with the new column filters whenever a filter widget changes.
+    #[props(default)]
+    pub on_filters_change: Option<EventHandler<ColumnFilters>>,
+
+    /// Sort keys to apply; when set, the table is controlled and only renders this sort.
+    #[props(default)]
+    pub sort: Option<Vec<SortKey>>,
+
+    /// Called with the new sort keys whenever a header is clicked.
+    #[props(default)]
+    pub on_sort_change: Option<EventHandler<Vec<SortKey>>>,
+
+    /// Zero-based page to show; when set, the table is controlled and only renders this page.
+    #[props(default)]
+    pub page: Option<usize>,
+
+    /// Called with the new page index whenever the table changes page, including the return to
+    /// the first page after the search or the filters change.
+    #[props(default)]
+    pub on_page_change: Option<EventHandler<usize>>,
+
+    /// Search query to apply; when set, the table is controlled and only renders this query.
+    #[props(default)]
+    pub search_query: Option<String>,
+
+    /// Called with the new search query once the user stops typing.
+    #[props(default)]
+    pub on_search_change: Option<EventHandler<String>>,
+
     /// Texts for various table UI messages.
     #[props(default)]
     pub texts: TableTexts,
@@ -166,13 +621,3 @@ pub struct TableProps {
     #[props(default)]
     pub classes: TableClasses,
 }
-
-/// Sort direction (ascending or descending).
-#[derive(PartialEq, Clone, Copy, Default)]
-pub enum SortOrder {
-    /// Ascending (default).
-    #[default]
-    Asc,
-    /// Descending.
-    Desc,
-}
@@ -1 +1,8 @@
+#![doc = include_str!("../LEPTOS.md")]
 
+pub mod body;
+pub mod controls;
+pub mod filters;
+pub mod header;
+pub mod table;
+pub mod types;
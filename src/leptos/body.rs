@@ -0,0 +1,135 @@
+use crate::core::fuzzy::{highlight_ranges, split_highlights};
+use crate::core::row::TableRow;
+use crate::leptos::types::Column;
+use crate::leptos::types::TableClasses;
+use crate::leptos::types::TableTexts;
+use leptos::prelude::*;
+
+/// A table body component that renders rows of data, along with loading and empty states.
+///
+/// This component is responsible for rendering the `<tbody>` section of a table in a Leptos application.
+/// It reactively displays row data, a loading message, or an empty state message based on the provided props.
+///
+/// # Props
+/// - `columns`: A `Vec<Column>` defining which fields to render in each table row. Each column corresponds to a key in the row data.
+/// - `rows`: A `Signal<Vec<T>>` representing the data for each row, where `T: TableRow` exposes cells by column ID.
+/// - `loading`: A `Signal<bool>` that, when true, displays a loading message instead of data rows.
+/// - `classes`: A `TableClasses` struct for customizing the CSS class names of the body, rows, and cells.
+/// - `texts`: A `TableTexts` struct that provides custom text for the loading and empty states.
+/// - `highlight`: A `Signal<String>` holding a fuzzy search query whose matches are wrapped in `<mark>` elements styled by `classes.highlight` (optional).
+///
+/// # Behavior
+/// - If `loading` is `true`, a single row with a loading message is shown spanning all columns.
+/// - If `rows` is empty and not loading, an empty message row is displayed.
+/// - Otherwise, each data row is rendered in a `<tr>`, with one `<td>` per column.
+///
+/// # Returns
+/// A Leptos view representing the `<tbody>` of a table, with reactive row content.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use maplit::hashmap;
+/// use table_rs::leptos::body::TableBody;
+/// use table_rs::leptos::types::{Column, TableClasses, TableTexts};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let rows = vec![
+///         hashmap! { "name" => "Ferris".to_string(), "email" => "ferris@opensass.org".to_string() },
+///         hashmap! { "name" => "Rustacean".to_string(), "email" => "rust@opensass.org".to_string() },
+///     ];
+///
+///     let columns = vec![
+///         Column { id: "name", header: "Name", ..Default::default() },
+///         Column { id: "email", header: "Email", ..Default::default() },
+///     ];
+///
+///     view! {
+///         <TableBody
+///             columns=columns
+///             rows=rows
+///             loading=false
+///             classes=TableClasses::default()
+///             texts=TableTexts::default()
+///         />
+///     }
+/// }
+/// ```
+///
+/// # See Also
+/// - [MDN `<tbody>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/tbody)
+#[component]
+pub fn TableBody<T>(
+    columns: Vec<Column>,
+    #[prop(into)] rows: Signal<Vec<T>>,
+    #[prop(into)] loading: Signal<bool>,
+    classes: TableClasses,
+    texts: TableTexts,
+    #[prop(into, optional)] highlight: Signal<String>,
+) -> impl IntoView
+where
+    T: TableRow + Clone + PartialEq + Send + Sync + 'static,
+{
+    let colspan = columns.len().to_string();
+
+    let content = move || {
+        if loading.get() {
+            view! {
+                <tr class=classes.loading_row>
+                    <td colspan=colspan.clone()>{texts.loading}</td>
+                </tr>
+            }
+            .into_any()
+        } else if rows.with(|rows| rows.is_empty()) {
+            view! {
+                <tr class=classes.empty_row>
+                    <td colspan=colspan.clone()>{texts.empty}</td>
+                </tr>
+            }
+            .into_any()
+        } else {
+            let highlight = highlight.get();
+            rows.get()
+                .into_iter()
+                .map(|row| {
+                    let cells = columns
+                        .iter()
+                        .map(|col| {
+                            let content = row.cell(col.id).unwrap_or_default();
+                            let ranges = highlight_ranges(&highlight, &content);
+                            let segments = split_highlights(&content, &ranges)
+                                .into_iter()
+                                .map(|(segment, marked)| {
+                                    let segment = segment.to_string();
+                                    if marked {
+                                        view! { <mark class=classes.highlight>{segment}</mark> }
+                                            .into_any()
+                                    } else {
+                                        segment.into_any()
+                                    }
+                                })
+                                .collect_view();
+                            view! {
+                                <td class=classes.body_cell role="cell">{segments}</td>
+                            }
+                        })
+                        .collect_view();
+
+                    view! {
+                        <tr class=classes.row role="row">
+                            {cells}
+                        </tr>
+                    }
+                })
+                .collect_view()
+                .into_any()
+        }
+    };
+
+    view! {
+        <tbody class=classes.tbody>
+            {content}
+        </tbody>
+    }
+}
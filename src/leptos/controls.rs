@@ -0,0 +1,60 @@
+use crate::leptos::types::TableClasses;
+use crate::leptos::types::TableTexts;
+use leptos::prelude::*;
+
+#[component]
+pub fn PaginationControls(
+    page: RwSignal<usize>,
+    #[prop(into)] total_pages: Signal<usize>,
+    #[prop(into, optional)] has_next: MaybeProp<bool>,
+    classes: TableClasses,
+    texts: TableTexts,
+) -> impl IntoView {
+    let on_prev = move |_| {
+        if page.get() > 0 {
+            page.set(page.get() - 1);
+        }
+    };
+
+    let has_next_page = move || {
+        has_next
+            .get()
+            .unwrap_or_else(|| page.get() + 1 < total_pages.get())
+    };
+
+    let on_next = move |_| {
+        if has_next_page() {
+            page.set(page.get() + 1);
+        }
+    };
+
+    let indicator = move || {
+        let indicator = match has_next.get() {
+            Some(_) => texts.cursor_indicator,
+            None => texts.page_indicator,
+        };
+        indicator
+            .replace("{current}", &(page.get() + 1).to_string())
+            .replace("{total}", &total_pages.get().to_string())
+    };
+
+    view! {
+        <div class=classes.pagination>
+            <button
+                class=classes.pagination_button
+                on:click=on_prev
+                disabled=move || page.get() == 0
+            >
+                {texts.previous_button}
+            </button>
+            <span>{indicator}</span>
+            <button
+                class=classes.pagination_button
+                on:click=on_next
+                disabled=move || !has_next_page()
+            >
+                {texts.next_button}
+            </button>
+        </div>
+    }
+}
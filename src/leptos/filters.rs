@@ -0,0 +1,147 @@
+use crate::core::filter::{ColumnFilter, ColumnFilters, FilterInput, FilterKind};
+use crate::leptos::types::Column;
+use crate::leptos::types::TableClasses;
+use crate::leptos::types::TableTexts;
+use leptos::prelude::*;
+
+/// A header row rendering one filter widget per column, according to each column's `filter`.
+///
+/// # Props
+/// - `columns`: A `Vec<Column>` whose `filter` fields select the widgets.
+/// - `filters`: A `Signal<ColumnFilters>` holding the current filters, shown in the widgets.
+/// - `on_filter`: A `Callback<(&'static str, Option<ColumnFilter>)>` called with the column ID and its new filter (`None` once it no longer restricts anything).
+/// - `classes`: A `TableClasses` struct allowing custom class names for the row, cells and inputs.
+/// - `texts`: A `TableTexts` struct providing the widget placeholders (optional).
+///
+/// # Behavior
+/// - `FilterKind::Text` renders a text input, `Number` and `Date` render two bound inputs, and `Select` renders a list of checkboxes.
+/// - Columns without a filter, or with a `Facet` menu shown in their header, get an empty cell.
+/// - The row holds no state; the parent applies the reported filters.
+///
+/// # Returns
+/// Returns a Leptos view containing a `<tr>` to render inside the table's `<thead>`.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use table_rs::core::filter::{ColumnFilters, FilterKind, set_filter};
+/// use table_rs::leptos::filters::FilterRow;
+/// use table_rs::leptos::types::{Column, TableClasses};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let columns = vec![
+///         Column { id: "age", header: "Age", filter: FilterKind::Number, ..Default::default() },
+///     ];
+///     let filters = RwSignal::new(ColumnFilters::new());
+///
+///     view! {
+///         <FilterRow
+///             columns=columns
+///             filters=filters
+///             on_filter=Callback::new(move |(id, filter)| {
+///                 filters.update(|filters| set_filter(filters, id, filter))
+///             })
+///             classes=TableClasses::default()
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn FilterRow(
+    columns: Vec<Column>,
+    #[prop(into)] filters: Signal<ColumnFilters>,
+    on_filter: Callback<(&'static str, Option<ColumnFilter>)>,
+    classes: TableClasses,
+    #[prop(optional)] texts: TableTexts,
+) -> impl IntoView {
+    let cells = columns
+        .into_iter()
+        .map(|col| {
+            let col_id = col.id;
+            let header = col.header;
+            let kind = col.filter.clone();
+            let emit = move |input: FilterInput| {
+                let filter =
+                    filters.with_untracked(|filters| kind.apply(filters.get(col_id), input));
+                on_filter.run((col_id, filter));
+            };
+            let current = move |read: fn(&ColumnFilter) -> String| {
+                filters.with(|filters| filters.get(col_id).map(read).unwrap_or_default())
+            };
+
+            let widget = match col.filter {
+                FilterKind::None | FilterKind::Facet => ().into_any(),
+                FilterKind::Text(_) => view! {
+                    <input
+                        class=classes.filter_input
+                        type="text"
+                        prop:value=move || current(|f| f.text().to_string())
+                        placeholder=texts.filter_placeholder
+                        aria-label=format!("Filter {header}")
+                        on:input=move |e| emit(FilterInput::Text(event_target_value(&e)))
+                    />
+                }
+                .into_any(),
+                FilterKind::Number | FilterKind::Date => {
+                    let input_type = if col.filter == FilterKind::Number {
+                        "number"
+                    } else {
+                        "date"
+                    };
+                    let emit_max = emit.clone();
+                    view! {
+                        <input
+                            class=classes.filter_input
+                            type=input_type
+                            prop:value=move || current(ColumnFilter::min_text)
+                            placeholder=texts.filter_min
+                            aria-label=format!("{header} {}", texts.filter_min)
+                            on:input=move |e| emit(FilterInput::Min(event_target_value(&e)))
+                        />
+                        <input
+                            class=classes.filter_input
+                            type=input_type
+                            prop:value=move || current(ColumnFilter::max_text)
+                            placeholder=texts.filter_max
+                            aria-label=format!("{header} {}", texts.filter_max)
+                            on:input=move |e| emit_max(FilterInput::Max(event_target_value(&e)))
+                        />
+                    }
+                    .into_any()
+                }
+                FilterKind::Select(options) => options
+                    .into_iter()
+                    .map(|option| {
+                        let emit = emit.clone();
+                        let checked = move || {
+                            filters.with(|filters| {
+                                filters.get(col_id).is_some_and(|f| f.is_selected(option))
+                            })
+                        };
+                        view! {
+                            <label>
+                                <input
+                                    class=classes.filter_input
+                                    type="checkbox"
+                                    prop:checked=checked
+                                    on:change=move |_| emit(FilterInput::Toggle(option.to_string()))
+                                />
+                                {option}
+                            </label>
+                        }
+                    })
+                    .collect_view()
+                    .into_any(),
+            };
+
+            view! { <th class=classes.filter_cell>{widget}</th> }
+        })
+        .collect_view();
+
+    view! {
+        <tr class=classes.filter_row role="row">
+            {cells}
+        </tr>
+    }
+}
@@ -0,0 +1,316 @@
+use crate::core::filter::{
+    ColumnFacets, ColumnFilter, ColumnFilters, Facet, FilterInput, FilterKind,
+};
+use crate::core::state::sort_priority;
+use crate::leptos::filters::FilterRow;
+use crate::leptos::types::Column;
+use crate::leptos::types::SortKey;
+use crate::leptos::types::SortOrder;
+use crate::leptos::types::TableClasses;
+use crate::leptos::types::TableTexts;
+use leptos::prelude::*;
+
+/// A table header component that renders sortable column headers for use within the `Table` component.
+///
+/// This component produces the `<thead>` section of a table using the provided column definitions,
+/// handling rendering, sorting indicators (`aria-sort`), and user interaction to trigger sort changes.
+///
+/// # Props
+/// - `columns`: A `Vec<Column>` defining the columns to display in the header. Each `Column` may be sortable and have optional styles or class overrides.
+/// - `sort`: A `Signal<Vec<SortKey>>` holding the sort stack, highest priority first.
+/// - `on_sort_column`: A `Callback<(&'static str, bool)>` triggered when a sortable header cell is clicked. The column ID and whether shift was held (to add a secondary sort key) are passed as the event payload.
+/// - `classes`: A `TableClasses` struct allowing custom class names for `<thead>`, `<tr>`, and `<th>` elements.
+/// - `texts`: A `TableTexts` struct providing the sort direction indicators and filter placeholders (optional).
+/// - `filters`: A `Signal<ColumnFilters>` holding the current filters, shown in the filter row (optional).
+/// - `on_filter`: A `Callback<(&'static str, Option<ColumnFilter>)>` called when a filter widget changes (optional).
+/// - `facets`: A `Signal<ColumnFacets>` listed by the facet menus (optional).
+///
+/// # Behavior
+/// - Sortable columns show proper `aria-sort` attributes for accessibility (`ascending`, `descending`, or `none`).
+/// - Clicking a sortable column emits an event to update sort state; shift-clicking adds or toggles a secondary key.
+/// - Sortable headers show a direction indicator (`texts.sort_ascending`, `texts.sort_descending` or `texts.sort_unsorted`) in a `<span>` styled by `classes.sort_indicator`.
+/// - When several columns are sorted, each sorted header shows its priority number in a `<span>` styled by `classes.sort_priority`.
+/// - When any column declares a `filter` widget, a `FilterRow` is rendered below the headers.
+/// - Columns using `FilterKind::Facet` get a `FacetMenu` in their header cell instead.
+/// - Each column can override default styles and classes via `Column::style` and `Column::class`.
+///
+/// # Returns
+/// Returns a Leptos view containing the `<thead>` with all column headers rendered as `<th>` elements.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use table_rs::leptos::header::TableHeader;
+/// use table_rs::leptos::types::{Column, SortOrder, TableClasses};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let columns = vec![
+///         Column { id: "name", header: "Name", sortable: true, ..Default::default() },
+///         Column { id: "email", header: "Email", sortable: false, ..Default::default() },
+///     ];
+///
+///     let sort = RwSignal::new(vec![("name", SortOrder::Asc)]);
+///
+///     view! {
+///         <TableHeader
+///             columns=columns
+///             sort=sort
+///             on_sort_column=Callback::new(move |(col_id, additive)| {
+///                 leptos::logging::log!("Sort column changed: {} (shift: {})", col_id, additive)
+///             })
+///             classes=TableClasses::default()
+///         />
+///     }
+/// }
+/// ```
+///
+/// # See Also
+/// - [MDN `<thead>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/thead)
+#[component]
+pub fn TableHeader(
+    columns: Vec<Column>,
+    #[prop(into)] sort: Signal<Vec<SortKey>>,
+    on_sort_column: Callback<(&'static str, bool)>,
+    classes: TableClasses,
+    #[prop(optional)] texts: TableTexts,
+    #[prop(into, optional)] filters: Signal<ColumnFilters>,
+    #[prop(optional)] on_filter: Option<Callback<(&'static str, Option<ColumnFilter>)>>,
+    #[prop(into, optional)] facets: Signal<ColumnFacets>,
+) -> impl IntoView {
+    let on_filter = Callback::new(move |change| {
+        if let Some(on_filter) = on_filter {
+            on_filter.run(change);
+        }
+    });
+    let filter_row = columns
+        .iter()
+        .any(|col| col.filter.in_filter_row())
+        .then(|| {
+            view! {
+                <FilterRow
+                    columns=columns.clone()
+                    filters=filters
+                    on_filter=on_filter
+                    classes=classes.clone()
+                    texts=texts.clone()
+                />
+            }
+        });
+
+    let header_cells = columns
+        .into_iter()
+        .map(|col| {
+            let col_id = col.id;
+            let sortable = col.sortable;
+            let aria_sort = move || {
+                sort.with(|sort| sort_priority(sort, col_id))
+                    .map_or("none", |(_, order)| order.aria())
+            };
+            let indicator = move || {
+                let text = match sort.with(|sort| sort_priority(sort, col_id)) {
+                    Some((_, SortOrder::Asc)) => texts.sort_ascending,
+                    Some((_, SortOrder::Desc)) => texts.sort_descending,
+                    None => texts.sort_unsorted,
+                };
+                (sortable && !text.is_empty()).then(|| {
+                    view! { <span class=classes.sort_indicator aria-hidden="true">{text}</span> }
+                })
+            };
+            let priority_number = move || {
+                sort.with(|sort| {
+                    sort_priority(sort, col_id)
+                        .filter(|_| sort.len() > 1)
+                        .map(|(n, _)| {
+                            view! { <span class=classes.sort_priority>{n}</span> }
+                        })
+                })
+            };
+
+            let class = format!("{} {}", classes.header_cell, col.class.unwrap_or_default())
+                .trim()
+                .to_string();
+            let style = col.style.unwrap_or_default();
+            let facet_menu = (col.filter == FilterKind::Facet).then(|| {
+                view! {
+                    <FacetMenu
+                        column=col_id
+                        header=col.header
+                        facets=Signal::derive(move || {
+                            facets.with(|facets| facets.get(col_id).cloned().unwrap_or_default())
+                        })
+                        filter=Signal::derive(move || {
+                            filters.with(|filters| filters.get(col_id).cloned())
+                        })
+                        on_filter=on_filter
+                        classes=classes.clone()
+                        texts=texts.clone()
+                    />
+                }
+            });
+
+            view! {
+                <th
+                    role="columnheader"
+                    class=class
+                    style=style
+                    aria-sort=aria_sort
+                    on:click=move |e| {
+                        if sortable {
+                            on_sort_column.run((col_id, e.shift_key()));
+                        }
+                    }
+                >
+                    {col.header}
+                    {indicator}
+                    {priority_number}
+                    {facet_menu}
+                </th>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <thead class=classes.thead>
+            <tr class=classes.row role="row">
+                {header_cells}
+            </tr>
+            {filter_row}
+        </thead>
+    }
+}
+
+/// An Excel-style dropdown menu listing the distinct values of a column with their row counts.
+///
+/// # Props
+/// - `column`: The ID of the column the menu filters.
+/// - `header`: The header text of the column, used to label the menu.
+/// - `facets`: A `Signal<Vec<Facet>>` with the distinct values of the column and their row counts.
+/// - `filter`: A `Signal<Option<ColumnFilter>>` holding the current filter of the column.
+/// - `on_filter`: A `Callback<(&'static str, Option<ColumnFilter>)>` called with the column ID and its new filter.
+/// - `classes`: A `TableClasses` struct allowing custom class names for the button, menu, values and counts.
+/// - `texts`: A `TableTexts` struct providing the menu labels (optional).
+///
+/// # Behavior
+/// - A button rendered in the column header opens and closes the menu.
+/// - Each value gets a checkbox, checked while its rows are shown; unchecking it excludes the value through a `ColumnFilter::NoneOf` filter.
+/// - A leading "select all" entry shows every value again, or hides them all.
+/// - Clicks inside the menu do not sort the column.
+///
+/// # Returns
+/// Returns a Leptos view containing the toggle button, followed by the menu while it is open.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use table_rs::core::filter::Facet;
+/// use table_rs::leptos::header::FacetMenu;
+/// use table_rs::leptos::types::TableClasses;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let facets = vec![
+///         Facet { value: "active".into(), count: 12 },
+///         Facet { value: "archived".into(), count: 3 },
+///     ];
+///
+///     view! {
+///         <FacetMenu
+///             column="status"
+///             header="Status"
+///             facets=facets
+///             filter=None
+///             on_filter=Callback::new(move |(id, filter)| {
+///                 leptos::logging::log!("{id}: {filter:?}")
+///             })
+///             classes=TableClasses::default()
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn FacetMenu(
+    column: &'static str,
+    header: &'static str,
+    #[prop(into)] facets: Signal<Vec<Facet>>,
+    #[prop(into)] filter: Signal<Option<ColumnFilter>>,
+    on_filter: Callback<(&'static str, Option<ColumnFilter>)>,
+    classes: TableClasses,
+    #[prop(optional)] texts: TableTexts,
+) -> impl IntoView {
+    let open = RwSignal::new(false);
+    let all_checked = move || filter.with(Option::is_none);
+
+    let on_select_all = move |_| {
+        let values = facets
+            .with_untracked(|facets| facets.iter().map(|facet| facet.value.clone()).collect());
+        let next = ColumnFilter::NoneOf(values);
+        let hide = filter.with_untracked(Option::is_none) && next.is_active();
+        on_filter.run((column, hide.then_some(next)));
+    };
+
+    let options = move || {
+        facets
+            .get()
+            .into_iter()
+            .map(|Facet { value, count }| {
+                let toggled = value.clone();
+                let excluded = {
+                    let value = value.clone();
+                    move || filter.with(|f| f.as_ref().is_some_and(|f| f.is_excluded(&value)))
+                };
+                let label = if value.is_empty() {
+                    texts.facet_blank.to_string()
+                } else {
+                    value
+                };
+                view! {
+                    <label class=classes.facet_option>
+                        <input
+                            type="checkbox"
+                            prop:checked=move || !excluded()
+                            on:change=move |_| {
+                                let input = FilterInput::Toggle(toggled.clone());
+                                let next = filter
+                                    .with_untracked(|f| FilterKind::Facet.apply(f.as_ref(), input));
+                                on_filter.run((column, next));
+                            }
+                        />
+                        {label}
+                        <span class=classes.facet_count>{count}</span>
+                    </label>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <button
+            type="button"
+            class=classes.facet_toggle
+            aria-haspopup="true"
+            aria-expanded=move || open.get().to_string()
+            aria-label=format!("Filter {header}")
+            on:click=move |e| {
+                e.stop_propagation();
+                open.update(|open| *open = !*open);
+            }
+        >
+            {texts.facet_toggle}
+        </button>
+        <Show when=move || open.get()>
+            <div
+                class=classes.facet_menu
+                role="group"
+                aria-label=format!("Filter {header}")
+                on:click=|e| e.stop_propagation()
+            >
+                <label class=classes.facet_option>
+                    <input type="checkbox" prop:checked=all_checked on:change=on_select_all />
+                    {texts.facet_select_all}
+                </label>
+                {options}
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,311 @@
+use leptos::prelude::*;
+#[cfg(target_family = "wasm")]
+use web_sys::UrlSearchParams;
+#[cfg(target_family = "wasm")]
+use web_sys::wasm_bindgen::JsValue;
+
+use crate::core::filter::{ColumnFacets, ColumnFilters, FilterKind, set_filter};
+use crate::core::model::TableModel;
+use crate::core::query::QueryError;
+use crate::core::row::TableRow;
+use crate::core::state::{DEFAULT_MAX_SORT_KEYS, TableState, toggle_sort_key};
+use crate::leptos::body::TableBody;
+use crate::leptos::controls::PaginationControls;
+use crate::leptos::header::TableHeader;
+use crate::leptos::types::Collation;
+use crate::leptos::types::Column;
+use crate::leptos::types::SearchMode;
+use crate::leptos::types::SortCycle;
+use crate::leptos::types::TableClasses;
+use crate::leptos::types::TableTexts;
+
+/// A fully featured table component with sorting, pagination, and search functionality in Leptos.
+///
+/// This component renders an interactive HTML `<table>` with customizable columns, data,
+/// class names, and labels. It supports client-side sorting, search with URL hydration,
+/// and pagination.
+///
+/// # Props
+/// - `data`: A `Signal<Vec<T>>` representing row data, where `T: TableRow`; a plain `Vec` is accepted too.
+/// - `columns`: A `Vec<Column>` describing each column's ID, header text, and behavior.
+/// - `page_size`: Number of rows to display per page (default: `10`).
+/// - `max_sort_keys`: Maximum number of columns sorted at once with shift-click (default: `3`).
+/// - `sort_cycle`: Whether header clicks can return a column to unsorted (default: `SortCycle::Toggle`).
+/// - `collation`: How text is compared when sorting (default: `Collation::Binary`).
+/// - `loading`: When `true`, displays a loading indicator (default: `false`).
+/// - `paginate`: Enables pagination controls (default: `false`).
+/// - `search`: Enables a search input for client-side filtering (default: `false`).
+/// - `search_mode`: Whether the search takes plain text, the query language (e.g. `status:active age:>30 -name:"test user"`) or fuzzy matching (default: `SearchMode::Plain`).
+/// - `on_search_error`: A `Callback<QueryError>` called when the query becomes invalid; the search then keeps every row (optional).
+/// - `fuzzy_rank`: Orders `SearchMode::Fuzzy` results by score while no column is sorted (default: `false`).
+/// - `filters`: A `Signal<ColumnFilters>` controlled by the parent; when unset, the table keeps its own filters (optional).
+/// - `on_filters_change`: A `Callback<ColumnFilters>` called with the new column filters whenever a filter widget changes (optional).
+/// - `texts`: Customizable text labels for UI strings (default: `TableTexts::default()`).
+/// - `classes`: Customizable CSS class names for each table part (default: `TableClasses::default()`).
+///
+/// # Features
+/// - **Search**: Filters rows client-side using a text input; the query is persisted in the URL via `?search=`. `SearchMode::Query` adds field-scoped terms, quoted phrases, negation, comparisons and `OR`, while `SearchMode::Fuzzy` tolerates typos and highlights the matches.
+/// - **Column Filters**: Columns declaring a `filter` widget get a filter row under the headers; filters combine with the search.
+/// - **Facet Menus**: `FilterKind::Facet` columns get a dropdown in their header listing each distinct value with its row count, updated as the other filters change.
+/// - **Sorting**: Clickable headers allow sorting columns ascending, descending or (optionally) unsorted, with direction indicators; shift-click sorts by several columns.
+/// - **Pagination**: Navigate between pages using prev/next buttons, with an indicator showing current page.
+/// - **Custom Classes**: All elements are styled via `TableClasses` for full customization.
+/// - **Text Overrides**: All UI strings (e.g., empty state, loading, buttons) can be customized using `TableTexts`.
+///
+/// # Returns
+/// Returns a Leptos view that renders a complete table with the above features.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use maplit::hashmap;
+/// use table_rs::leptos::table::Table;
+/// use table_rs::leptos::types::Column;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let data = vec![
+///         hashmap! { "name" => "ferris".to_string(), "email" => "ferris@opensass.org".to_string() },
+///         hashmap! { "name" => "ferros".to_string(), "email" => "ferros@opensass.org".to_string() },
+///     ];
+///
+///     let columns = vec![
+///         Column { id: "name", header: "Name", sortable: true, ..Default::default() },
+///         Column { id: "email", header: "Email", ..Default::default() },
+///     ];
+///
+///     view! {
+///         <Table data=data columns=columns paginate=true search=true />
+///     }
+/// }
+/// ```
+///
+/// Rows can also be typed structs deriving `TableRow` (requires the `derive` feature):
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # mod example {
+/// use leptos::prelude::*;
+/// use table_rs::TableRow;
+/// use table_rs::core::row::TableRow as _;
+/// use table_rs::leptos::table::Table;
+/// use table_rs::leptos::types::Column;
+///
+/// #[derive(Clone, PartialEq, TableRow)]
+/// struct User {
+///     #[table(sortable)]
+///     name: String,
+///     #[table(header = "Email", sortable)]
+///     email: String,
+///     age: u32,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let data = vec![
+///         User { name: "Ferris".into(), email: "ferris@opensass.org".into(), age: 12 },
+///         User { name: "Crab".into(), email: "crab@opensass.org".into(), age: 7 },
+///     ];
+///     let columns: Vec<Column> = User::columns();
+///
+///     view! {
+///         <Table data=data columns=columns paginate=true />
+///     }
+/// }
+/// # }
+/// ```
+///
+/// # See Also
+/// - [MDN `<table>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/table)
+#[component]
+pub fn Table<T>(
+    #[prop(into, optional)] data: Signal<Vec<T>>,
+    #[prop(optional)] columns: Vec<Column>,
+    #[prop(default = 10)] page_size: usize,
+    #[prop(default = DEFAULT_MAX_SORT_KEYS)] max_sort_keys: usize,
+    #[prop(optional)] sort_cycle: SortCycle,
+    #[prop(optional)] collation: Collation,
+    #[prop(into, optional)] loading: Signal<bool>,
+    #[prop(optional)] paginate: bool,
+    #[prop(optional)] search: bool,
+    #[prop(optional)] search_mode: SearchMode,
+    #[prop(optional)] on_search_error: Option<Callback<QueryError>>,
+    #[prop(optional)] fuzzy_rank: bool,
+    #[prop(into, optional)] filters: Option<Signal<ColumnFilters>>,
+    #[prop(optional)] on_filters_change: Option<Callback<ColumnFilters>>,
+    #[prop(optional)] texts: TableTexts,
+    #[prop(optional)] classes: TableClasses,
+) -> impl IntoView
+where
+    T: TableRow + Clone + PartialEq + Send + Sync + 'static,
+{
+    let page = RwSignal::new(0_usize);
+    let sort = RwSignal::new(Vec::new());
+    let filter_state = RwSignal::new(ColumnFilters::new());
+    let filters = filters.unwrap_or_else(|| filter_state.into());
+    let search_query = RwSignal::new(String::new());
+
+    #[cfg(target_family = "wasm")]
+    {
+        let window = web_sys::window().unwrap();
+        let location = window.location();
+        let search = location.search().unwrap_or_default();
+        let params = UrlSearchParams::new_with_str(&search).unwrap();
+        if let Some(search_val) = params.get("search") {
+            search_query.set(search_val);
+        }
+    }
+
+    #[cfg(target_family = "wasm")]
+    let update_search_param = move |query: &str| {
+        let window = web_sys::window().unwrap();
+        let href = window.location().href().unwrap();
+        let url = web_sys::Url::new(&href).unwrap();
+        let params = url.search_params();
+        params.set("search", query);
+        url.set_search(&params.to_string().as_string().unwrap_or_default());
+
+        window
+            .history()
+            .unwrap()
+            .replace_state_with_url(&JsValue::NULL, "", Some(&url.href()))
+            .unwrap();
+    };
+
+    let model_columns = columns.clone();
+    let view = Memo::new(move |_| {
+        let state = TableState {
+            query: search_query.get(),
+            search_mode,
+            fuzzy_rank,
+            filters: filters.get(),
+            sort: sort.get(),
+            max_sort_keys,
+            sort_cycle,
+            page: page.get(),
+            page_size,
+        };
+        data.with(|data| {
+            let view = TableModel::new(data, &model_columns)
+                .with_collation(collation)
+                .view(&state);
+            (
+                view.rows.into_iter().cloned().collect::<Vec<_>>(),
+                view.total_pages,
+                view.search_error,
+                view.page,
+            )
+        })
+    });
+
+    let facet_columns = columns.clone();
+    let facets = Memo::new(move |_| {
+        let state = TableState {
+            query: search_query.get(),
+            search_mode,
+            filters: filters.get(),
+            ..Default::default()
+        };
+        data.with(|data| {
+            let model = TableModel::new(data, &facet_columns).with_collation(collation);
+            facet_columns
+                .iter()
+                .filter(|col| col.filter == FilterKind::Facet)
+                .map(|col| (col.id, model.facets(col.id, &state)))
+                .collect::<ColumnFacets>()
+        })
+    });
+
+    let search_error = Memo::new(move |_| view.with(|(_, _, error, _)| error.clone()));
+    Effect::new(move |_| {
+        if let (Some(error), Some(on_search_error)) = (search_error.get(), on_search_error) {
+            on_search_error.run(error);
+        }
+    });
+
+    let highlight = Signal::derive(move || match search_mode {
+        SearchMode::Fuzzy => search_query.get(),
+        _ => String::new(),
+    });
+    let total_pages = Signal::derive(move || view.with(|(_, total_pages, _, _)| *total_pages));
+    let page_rows = Signal::derive(move || view.with(|(rows, _, _, _)| rows.clone()));
+
+    // Once the rows are known, fall back to the last page when they no longer reach the current
+    // one, e.g. after `data` shrank.
+    Effect::new(move |_| {
+        let shown_page = view.with(|(_, _, _, page)| *page);
+        if !loading.get() && shown_page != page.get_untracked() {
+            page.set(shown_page);
+        }
+    });
+
+    let on_sort_column = Callback::new(move |(id, additive): (&'static str, bool)| {
+        sort.update(|sort| toggle_sort_key(sort, id, additive, max_sort_keys, sort_cycle));
+    });
+
+    let on_filter = Callback::new(move |(id, filter)| {
+        let mut next = filters.get_untracked();
+        set_filter(&mut next, id, filter);
+        filter_state.set(next.clone());
+        if let Some(on_filters_change) = on_filters_change {
+            on_filters_change.run(next);
+        }
+        page.set(0);
+    });
+
+    let search_input = search.then(|| {
+        view! {
+            <input
+                class=classes.search_input
+                type="text"
+                prop:value=move || search_query.get()
+                placeholder=texts.search_placeholder
+                aria-label="Search table"
+                on:input=move |e| {
+                    let val = event_target_value(&e);
+                    #[cfg(target_family = "wasm")]
+                    update_search_param(&val);
+                    search_query.set(val);
+                    page.set(0);
+                }
+            />
+        }
+    });
+
+    let pagination_controls = paginate.then(|| {
+        view! {
+            <PaginationControls
+                page=page
+                total_pages=total_pages
+                classes=classes.clone()
+                texts=texts.clone()
+            />
+        }
+    });
+
+    view! {
+        <div class=classes.container>
+            {search_input}
+            <table class=classes.table role="table">
+                <TableHeader
+                    columns=columns.clone()
+                    sort=sort
+                    on_sort_column=on_sort_column
+                    classes=classes.clone()
+                    texts=texts.clone()
+                    filters=filters
+                    on_filter=on_filter
+                    facets=facets
+                />
+                <TableBody
+                    columns=columns
+                    rows=page_rows
+                    loading=loading
+                    classes=classes.clone()
+                    texts=texts.clone()
+                    highlight=highlight
+                />
+            </table>
+            {pagination_controls}
+        </div>
+    }
+}
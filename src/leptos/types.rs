@@ -0,0 +1,295 @@
+use crate::core::compare::{Comparator, SortKeyFn};
+use crate::core::filter::FilterKind;
+use crate::core::model::ColumnSpec;
+use crate::core::row::ColumnMeta;
+use crate::core::value::{CellKind, NullOrder};
+
+pub use crate::core::compare::Collation;
+pub use crate::core::normalize::Normalizer;
+pub use crate::core::state::{SearchMode, SortCycle, SortKey, SortOrder};
+
+/// Represents a column definition for the table.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Column {
+    /// Unique identifier for the column.
+    pub id: &'static str,
+
+    /// Header text displayed in the column.
+    pub header: &'static str,
+
+    /// Whether this column is sortable.
+    pub sortable: bool,
+
+    /// Minimum width of the column (default is 100).
+    pub min_width: u32,
+
+    /// Optional inline styles for the column header.
+    pub style: Option<&'static str>,
+
+    /// Optional CSS classes for the column header.
+    pub class: Option<&'static str>,
+
+    /// How cell values are interpreted when sorting (numbers, dates, booleans...).
+    pub kind: CellKind,
+
+    /// Where empty cells go when sorting, regardless of the sort direction.
+    pub nulls: NullOrder,
+
+    /// Maps cell values to the keys they are sorted by, e.g. to rank severity levels.
+    pub sort_key: Option<SortKeyFn>,
+
+    /// Custom ordering used instead of the built-in comparison when sorting, e.g. for versions.
+    pub comparator: Option<Comparator>,
+
+    /// Collation used to compare text when sorting; defaults to the table's `collation`.
+    pub collation: Option<Collation>,
+
+    /// Filter widget rendered for the column in the header's filter row.
+    pub filter: FilterKind,
+
+    /// Whether the global search looks at the column; turn off for ids or timestamps.
+    pub searchable: bool,
+
+    /// How the plain search normalizes the column's cells and the query, e.g. to ignore accents.
+    pub normalizer: Normalizer,
+}
+
+impl Default for Column {
+    fn default() -> Self {
+        Self {
+            id: "",
+            header: "",
+            sortable: false,
+            min_width: 100,
+            style: None,
+            class: None,
+            kind: CellKind::Auto,
+            nulls: NullOrder::Last,
+            sort_key: None,
+            comparator: None,
+            collation: None,
+            filter: FilterKind::None,
+            searchable: true,
+            normalizer: Normalizer::CaseFold,
+        }
+    }
+}
+
+impl ColumnSpec for Column {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn kind(&self) -> CellKind {
+        self.kind
+    }
+
+    fn nulls(&self) -> NullOrder {
+        self.nulls
+    }
+
+    fn sort_key(&self) -> Option<&SortKeyFn> {
+        self.sort_key.as_ref()
+    }
+
+    fn comparator(&self) -> Option<&Comparator> {
+        self.comparator.as_ref()
+    }
+
+    fn collation(&self) -> Option<Collation> {
+        self.collation
+    }
+
+    fn searchable(&self) -> bool {
+        self.searchable
+    }
+
+    fn normalizer(&self) -> Normalizer {
+        self.normalizer
+    }
+}
+
+impl From<ColumnMeta> for Column {
+    fn from(meta: ColumnMeta) -> Self {
+        Self {
+            id: meta.id,
+            header: meta.header,
+            sortable: meta.sortable,
+            ..Default::default()
+        }
+    }
+}
+
+/// Text labels for table UI elements.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableTexts {
+    /// Text shown when data is loading.
+    pub loading: &'static str,
+
+    /// Text shown when no data is available.
+    pub empty: &'static str,
+
+    /// Placeholder text for the search input.
+    pub search_placeholder: &'static str,
+
+    /// Label for the previous page button.
+    pub previous_button: &'static str,
+
+    /// Label for the next page button.
+    pub next_button: &'static str,
+
+    /// Page indicator text with placeholders `{current}` and `{total}`.
+    pub page_indicator: &'static str,
+
+    /// Page indicator text of cursor pagination, where the total is unknown, with `{current}`.
+    pub cursor_indicator: &'static str,
+
+    /// Indicator shown in the header of a column sorted in ascending order.
+    pub sort_ascending: &'static str,
+
+    /// Indicator shown in the header of a column sorted in descending order.
+    pub sort_descending: &'static str,
+
+    /// Indicator shown in the header of a sortable column that is not sorted; hidden when empty.
+    pub sort_unsorted: &'static str,
+
+    /// Placeholder for the text column filters.
+    pub filter_placeholder: &'static str,
+
+    /// Placeholder for the lower bound of number column filters.
+    pub filter_min: &'static str,
+
+    /// Placeholder for the upper bound of number column filters.
+    pub filter_max: &'static str,
+
+    /// Label of the button opening a column's facet menu.
+    pub facet_toggle: &'static str,
+
+    /// Label of the facet menu entry checking or unchecking every value.
+    pub facet_select_all: &'static str,
+
+    /// Label of the facet value standing for empty cells.
+    pub facet_blank: &'static str,
+}
+
+impl Default for TableTexts {
+    fn default() -> Self {
+        Self {
+            loading: "Loading...",
+            empty: "No results found",
+            search_placeholder: "Search...",
+            previous_button: "Previous",
+            next_button: "Next",
+            page_indicator: "Page {current} of {total}",
+            cursor_indicator: "Page {current}",
+            sort_ascending: "▲",
+            sort_descending: "▼",
+            sort_unsorted: "",
+            filter_placeholder: "Filter...",
+            filter_min: "Min",
+            filter_max: "Max",
+            facet_toggle: "▾",
+            facet_select_all: "(Select all)",
+            facet_blank: "(Blanks)",
+        }
+    }
+}
+
+/// Defines the styling classes for each part of the table.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableClasses {
+    /// Wrapper around the entire table.
+    pub container: &'static str,
+
+    /// Class for the `<table>` element.
+    pub table: &'static str,
+
+    /// Class for the `<thead>` element.
+    pub thead: &'static str,
+
+    /// Class for the `<tbody>` element.
+    pub tbody: &'static str,
+
+    /// Wrapper for pagination controls.
+    pub pagination: &'static str,
+
+    /// Class for the search input field.
+    pub search_input: &'static str,
+
+    /// Class for header cells (`<th>`).
+    pub header_cell: &'static str,
+
+    /// Class for body cells (`<td>`).
+    pub body_cell: &'static str,
+
+    /// Class for each table row.
+    pub row: &'static str,
+
+    /// Class for the row shown while loading.
+    pub loading_row: &'static str,
+
+    /// Class for the row shown when no data is found.
+    pub empty_row: &'static str,
+
+    /// Class for pagination buttons.
+    pub pagination_button: &'static str,
+
+    /// Class for the sort priority number shown in headers when sorting by several columns.
+    pub sort_priority: &'static str,
+
+    /// Class for the sort direction indicator shown in sortable headers.
+    pub sort_indicator: &'static str,
+
+    /// Class for the header row holding the column filters.
+    pub filter_row: &'static str,
+
+    /// Class for the cells of the filter row.
+    pub filter_cell: &'static str,
+
+    /// Class for the inputs of the column filters.
+    pub filter_input: &'static str,
+
+    /// Class for the button opening a column's facet menu.
+    pub facet_toggle: &'static str,
+
+    /// Class for the dropdown listing a column's facets.
+    pub facet_menu: &'static str,
+
+    /// Class for each value of a facet menu.
+    pub facet_option: &'static str,
+
+    /// Class for the row count shown next to each facet value.
+    pub facet_count: &'static str,
+
+    /// Class for the `<mark>` highlighting fuzzy search matches in cells.
+    pub highlight: &'static str,
+}
+
+impl Default for TableClasses {
+    fn default() -> Self {
+        Self {
+            container: "table-container",
+            table: "table",
+            thead: "thead",
+            tbody: "tbody",
+            pagination: "pagination-controls",
+            search_input: "search-input",
+            header_cell: "th",
+            body_cell: "td",
+            row: "tr",
+            loading_row: "loading-row",
+            empty_row: "empty-row",
+            pagination_button: "pagination-button",
+            sort_priority: "sort-priority",
+            sort_indicator: "sort-indicator",
+            filter_row: "filter-row",
+            filter_cell: "filter-cell",
+            filter_input: "filter-input",
+            facet_toggle: "facet-toggle",
+            facet_menu: "facet-menu",
+            facet_option: "facet-option",
+            facet_count: "facet-count",
+            highlight: "search-highlight",
+        }
+    }
+}
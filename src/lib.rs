@@ -5,6 +5,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 
+pub mod core;
+
+#[cfg(feature = "derive")]
+pub use table_rs_derive::TableRow;
+
 #[cfg(feature = "yew")]
 pub mod yew;
 
@@ -1,4 +1,14 @@
+use crate::core::fuzzy::{highlight_ranges, split_highlights};
+use crate::core::row::TableRow;
+use crate::core::virtualize::{
+    ColumnSlot, RowHeights, VirtualWindow, column_slots, shows_appended_rows,
+};
 use crate::yew::types::TableBodyProps;
+use std::collections::HashMap;
+use web_sys::js_sys::Array;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::{IntersectionObserver, IntersectionObserverEntry};
 use yew::prelude::*;
 
 /// A table body component that handles rendering of table rows, empty state, and loading state.
@@ -9,16 +19,33 @@ use yew::prelude::*;
 /// # Arguments
 /// * `props` - The properties passed to the component.
 ///   - `columns` - A list of column definitions (`Vec<Column>`) specifying which fields to render.
-///   - `rows` - A vector of row data (`Vec<HashMap<&'static str, String>>`) to display.
+///   - `rows` - The row data (`SharedRows<T>`, where `T: TableRow`), converted from a `Vec<T>`.
+///   - `indices` - The indices into `rows` of the rows to display, in order; every row when
+///     `None`.
 ///   - `loading` - A boolean flag indicating whether the table is in a loading state.
 ///   - `classes` - A `TableClasses` object defining CSS class names for customization.
 ///   - `texts` - A `TableTexts` object defining UI text like loading or empty messages.
+///   - `highlight` - A fuzzy search query whose matches are wrapped in `<mark>` elements styled by
+///     `classes.highlight`; empty for none.
+///   - `error` - The message of a failed fetch, replacing the rows with an error row when set.
+///   - `on_retry` - A `Callback<()>` triggered by the retry button of the error row.
+///   - `has_more` - A boolean flag ending the rows with a "loading more" row, styled by
+///     `classes.loading_row`.
+///   - `on_load_more` - A `Callback<()>` triggered whenever that row scrolls into view.
+///   - `virtualize` - A boolean flag rendering only the rows between `scroll_top` and
+///     `scroll_top + viewport_height`, plus `overscan` rows on each side, with spacer rows
+///     styled by `classes.spacer_row` standing for the others.
+///   - `row_height` - The height of each row in pixels, or their estimate when `measure_rows`
+///     measures the rendered rows.
+///   - `column_window` - The columns to render when they are virtualized, with spacer cells
+///     standing for the others; every column when `None`.
 ///
 /// # Returns
 /// (Html): A rendered `<tbody>` element, containing:
 ///   - A loading row if `loading` is `true`.
-///   - An empty state row if `rows` is empty.
-///   - The list of rows otherwise.
+///   - An error row with a retry button if `error` is set.
+///   - An empty state row if there are no rows to display and `has_more` is `false`.
+///   - The list of rows otherwise, followed by the "loading more" row if `has_more` is `true`.
 ///
 /// # Examples
 /// ```rust
@@ -41,10 +68,23 @@ use yew::prelude::*;
 ///    
 ///     let props = TableBodyProps {
 ///         columns,
-///         rows,
+///         rows: rows.into(),
+///         indices: Some(vec![1, 0].into()),
 ///         loading: false,
 ///         classes: Default::default(),
 ///         texts: Default::default(),
+///         highlight: "fer".into(),
+///         error: None,
+///         on_retry: Callback::noop(),
+///         has_more: false,
+///         on_load_more: Callback::noop(),
+///         virtualize: false,
+///         row_height: 36.0,
+///         measure_rows: false,
+///         overscan: 5,
+///         scroll_top: 0.0,
+///         viewport_height: 480.0,
+///         column_window: None,
 ///     };
 ///    
 ///     html! {
@@ -56,36 +96,190 @@ use yew::prelude::*;
 /// # See Also
 /// - [MDN tbody Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/tbody)
 #[function_component(TableBody)]
-pub fn body(props: &TableBodyProps) -> Html {
+pub fn body<T = HashMap<&'static str, String>>(props: &TableBodyProps<T>) -> Html
+where
+    T: TableRow + Clone + PartialEq + 'static,
+{
     let TableBodyProps {
         columns,
         rows,
+        indices,
         loading,
         classes,
         texts,
+        highlight,
+        error,
+        on_retry,
+        has_more,
+        on_load_more,
+        virtualize,
+        row_height,
+        measure_rows,
+        overscan,
+        scroll_top,
+        viewport_height,
+        column_window,
     } = props;
 
+    let count = indices.as_ref().map_or(rows.len(), |indices| indices.len());
+    let row_at = |position: usize| &rows[indices.as_ref().map_or(position, |i| i[position])];
+    let heights = use_state(|| RowHeights::new(*row_height));
+    {
+        let heights = heights.clone();
+        use_effect_with(*row_height, move |row_height| {
+            heights.set(RowHeights::new(*row_height));
+        });
+    }
+    {
+        // Measurements are kept as rows are appended, e.g. by infinite scroll, and dropped once
+        // other rows may be shown at the measured positions.
+        let heights = heights.clone();
+        let shown = use_mut_ref(|| (rows.clone(), indices.clone()));
+        use_effect_with((rows.clone(), indices.clone()), move |(rows, indices)| {
+            let mut shown = shown.borrow_mut();
+            let (shown_rows, shown_indices) = &*shown;
+            if !shows_appended_rows(shown_rows, shown_indices.as_ref(), rows, indices.as_ref())
+                && heights.is_measured()
+            {
+                let mut cleared = (*heights).clone();
+                cleared.clear();
+                heights.set(cleared);
+            }
+            *shown = (rows.clone(), indices.clone());
+        });
+    }
+    let tbody = use_node_ref();
+    {
+        let tbody = tbody.clone();
+        let heights = heights.clone();
+        let measure = *virtualize && *measure_rows;
+        // Rendered rows are measured after every render, until their heights settle.
+        use_effect(move || {
+            if let (true, Some(tbody)) = (measure, tbody.cast::<web_sys::Element>()) {
+                let mut measured = (*heights).clone();
+                let mut changed = false;
+                let children = tbody.children();
+                for row in (0..children.length()).filter_map(|i| children.item(i)) {
+                    if let Some(index) = row.get_attribute("data-row").and_then(|i| i.parse().ok())
+                    {
+                        changed |= measured.measure(index, row.get_bounding_client_rect().height());
+                    }
+                }
+                if changed {
+                    heights.set(measured);
+                }
+            }
+        });
+    }
+    let slots = column_slots(columns, column_window.as_ref());
+    let window = if *virtualize {
+        heights.window(count, *scroll_top, *viewport_height, *overscan)
+    } else {
+        VirtualWindow {
+            end: count,
+            ..Default::default()
+        }
+    };
+
+    let sentinel = use_node_ref();
+    let observe = *has_more && !*loading && error.is_none();
+    {
+        let sentinel = sentinel.clone();
+        let on_load_more = on_load_more.clone();
+        // A new observer reports whether the sentinel is visible right away, so observing again
+        // once rows were added keeps loading until they fill the viewport.
+        use_effect_with((observe, count), move |_| {
+            // Without IntersectionObserver support the row stays static and loads nothing.
+            let observer = sentinel.cast::<web_sys::Element>().and_then(|element| {
+                let callback = Closure::<dyn FnMut(Array)>::new(move |entries: Array| {
+                    let visible = entries.iter().any(|entry| {
+                        entry
+                            .unchecked_into::<IntersectionObserverEntry>()
+                            .is_intersecting()
+                    });
+                    if visible {
+                        on_load_more.emit(());
+                    }
+                });
+                let observer = IntersectionObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                observer.observe(&element);
+                Some((observer, callback))
+            });
+            move || {
+                if let Some((observer, _callback)) = observer {
+                    observer.disconnect();
+                }
+            }
+        });
+    }
+
     html! {
-        <tbody class={classes.tbody}>
+        <tbody class={classes.tbody} ref={tbody}>
             { if *loading {
                     html! {
-                        <tr class={classes.loading_row}><td colspan={columns.len().to_string()}>{ texts.loading }</td></tr>
+                        <tr class={classes.loading_row}><td colspan={slots.len().to_string()}>{ texts.loading }</td></tr>
                     }
-                } else if rows.is_empty() {
+                } else if let Some(error) = error {
+                    let on_retry = on_retry.reform(|_| ());
                     html! {
-                        <tr class={classes.empty_row}><td colspan={columns.len().to_string()}>{ texts.empty }</td></tr>
+                        <tr class={classes.error_row} role="alert">
+                            <td colspan={slots.len().to_string()} title={error.clone()}>
+                                { texts.error }
+                                <button class={classes.retry_button} onclick={on_retry}>{ texts.retry }</button>
+                            </td>
+                        </tr>
+                    }
+                } else if count == 0 && !*has_more {
+                    html! {
+                        <tr class={classes.empty_row}><td colspan={slots.len().to_string()}>{ texts.empty }</td></tr>
                     }
                 } else {
                     html! {
-                        for row in rows.iter() {
-                                <tr class={classes.row} role="row">
-                                        for col in columns.iter() {
-                                                <td class={classes.body_cell} role="cell">{ row.get(col.id).unwrap_or(&"".to_string()) }</td>
-                                        }
-                                </tr>
+                        <>
+                        if window.before > 0.0 {
+                            <tr class={classes.spacer_row} aria-hidden="true" style={format!("height: {}px", window.before)} />
+                        }
+                        for (position, row) in (window.start..window.end).map(|p| (p, row_at(p))) {
+                            <tr class={classes.row} role="row" data-row={position.to_string()}>
+                                for slot in slots.iter() {
+                                    { match slot {
+                                        ColumnSlot::Column(col, style) => html! {
+                                            <td class={classes.body_cell} role="cell" style={style.clone()}>
+                                                { highlighted(&row.cell(col.id).unwrap_or_default(), highlight, classes.highlight) }
+                                            </td>
+                                        },
+                                        ColumnSlot::Spacer(style) => html! {
+                                            <td aria-hidden="true" style={style.clone()} />
+                                        },
+                                    } }
+                                }
+                            </tr>
+                        }
+                        if window.after > 0.0 {
+                            <tr class={classes.spacer_row} aria-hidden="true" style={format!("height: {}px", window.after)} />
+                        }
+                        if *has_more {
+                            <tr class={classes.loading_row} ref={sentinel}>
+                                <td colspan={slots.len().to_string()}>{ texts.loading_more }</td>
+                            </tr>
                         }
+                        </>
                     }
                 } }
         </tbody>
     }
 }
+
+/// Renders `text`, wrapping the matches of the fuzzy `query` in `<mark>` elements.
+fn highlighted(text: &str, query: &str, class: &'static str) -> Html {
+    let ranges = highlight_ranges(query, text);
+    html! {
+        for (segment, marked) in split_highlights(text, &ranges) {
+            if marked {
+                <mark {class}>{ segment }</mark>
+            } else {
+                { segment }
+            }
+        }
+    }
+}
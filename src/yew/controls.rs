@@ -1,3 +1,4 @@
+use crate::core::pagination::{PageItem, page_window, parse_page, row_range};
 use crate::yew::types::PaginationControlsProps;
 use yew::prelude::*;
 
@@ -5,43 +6,167 @@ use yew::prelude::*;
 pub fn pagination_controls(props: &PaginationControlsProps) -> Html {
     let PaginationControlsProps {
         page,
+        on_page_change,
         total_pages,
+        has_next,
+        rich,
+        page_size,
+        page_sizes,
+        on_page_size_change,
+        total_rows,
         classes,
         texts,
     } = props;
-    let page_val = **page;
+    let page_val = *page;
+    let has_next_page = has_next.unwrap_or(page_val + 1 < *total_pages);
+    let indicator = match has_next {
+        Some(_) => texts.cursor_indicator,
+        None => texts.page_indicator,
+    };
+    // Numbered pages, "Last" and the page jump need a known page count.
+    let counted = *rich && has_next.is_none();
+
+    let go_to = |target: usize| {
+        let on_page_change = on_page_change.clone();
+        Callback::from(move |_: MouseEvent| on_page_change.emit(target))
+    };
 
     let on_prev = {
-        let page = page.clone();
+        let on_page_change = on_page_change.clone();
         Callback::from(move |_| {
-            if *page > 0 {
-                page.set(*page - 1);
+            if page_val > 0 {
+                on_page_change.emit(page_val - 1);
             }
         })
     };
 
     let on_next = {
-        let page = page.clone();
+        let on_page_change = on_page_change.clone();
         Callback::from(move |_| {
-            page.set(*page + 1);
+            if has_next_page {
+                on_page_change.emit(page_val + 1);
+            }
         })
     };
 
+    let on_jump = {
+        let on_page_change = on_page_change.clone();
+        let total_pages = *total_pages;
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Some(target) = parse_page(&input.value(), total_pages) {
+                on_page_change.emit(target);
+            }
+            input.set_value("");
+        })
+    };
+
+    let on_page_size = {
+        let on_page_size_change = on_page_size_change.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(size) = select.value().parse() {
+                on_page_size_change.emit(size);
+            }
+        })
+    };
+
+    let indicator = if counted {
+        html! {
+            <span>
+                for item in page_window(page_val, *total_pages, 1) {
+                    { match item {
+                        PageItem::Page(target) if target == page_val => html! {
+                            <button
+                                class={classes!(classes.page_button, classes.page_button_active)}
+                                aria-current="page"
+                                disabled=true
+                            >
+                                { target + 1 }
+                            </button>
+                        },
+                        PageItem::Page(target) => html! {
+                            <button class={classes.page_button} onclick={go_to(target)}>
+                                { target + 1 }
+                            </button>
+                        },
+                        PageItem::Ellipsis => html! {
+                            <span class={classes.page_ellipsis}>{ texts.page_ellipsis }</span>
+                        },
+                    } }
+                }
+            </span>
+        }
+    } else {
+        html! {
+            <span>
+                { indicator.replace("{current}", &(page_val + 1).to_string()).replace("{total}", &total_pages.to_string()) }
+            </span>
+        }
+    };
+
+    let range = total_rows.filter(|_| counted).and_then(|total| {
+        let (start, end) = row_range(page_val, *page_size, total)?;
+        Some(
+            texts
+                .range_indicator
+                .replace("{start}", &start.to_string())
+                .replace("{end}", &end.to_string())
+                .replace("{total}", &total.to_string()),
+        )
+    });
+
     html! {
         <div class={classes.pagination}>
+            if *rich {
+                <button class={classes.pagination_button} onclick={go_to(0)} disabled={page_val == 0}>
+                    { texts.first_button }
+                </button>
+            }
             <button class={classes.pagination_button} onclick={on_prev} disabled={page_val == 0}>
                 { texts.previous_button }
             </button>
-            <span>
-                { texts.page_indicator.replace("{current}", &(page_val + 1).to_string()).replace("{total}", &total_pages.to_string()) }
-            </span>
+            { indicator }
             <button
                 class={classes.pagination_button}
                 onclick={on_next}
-                disabled={page_val + 1 >= *total_pages}
+                disabled={!has_next_page}
             >
                 { texts.next_button }
             </button>
+            if counted {
+                <button
+                    class={classes.pagination_button}
+                    onclick={go_to(total_pages.saturating_sub(1))}
+                    disabled={!has_next_page}
+                >
+                    { texts.last_button }
+                </button>
+                <input
+                    class={classes.page_jump}
+                    type="number"
+                    min="1"
+                    max={total_pages.to_string()}
+                    placeholder={texts.page_jump}
+                    aria-label={texts.page_jump}
+                    onchange={on_jump}
+                />
+            }
+            if !page_sizes.is_empty() {
+                <label>
+                    { texts.page_size_label }
+                    <select class={classes.page_size_select} onchange={on_page_size}>
+                        for size in page_sizes.iter().copied() {
+                            <option value={size.to_string()} selected={size == *page_size}>
+                                { size }
+                            </option>
+                        }
+                    </select>
+                </label>
+            }
+            if let Some(range) = range {
+                <span class={classes.pagination_range}>{ range }</span>
+            }
         </div>
     }
 }
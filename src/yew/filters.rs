@@ -0,0 +1,154 @@
+use crate::core::filter::{FilterInput, FilterKind};
+use crate::core::virtualize::{ColumnSlot, column_slots};
+use crate::yew::types::FilterRowProps;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+/// A header row rendering one filter widget per column, according to each column's `filter`.
+///
+/// The widget of a column depends on its `FilterKind`: a text input for `Text`, two number
+/// inputs for `Number`, two date inputs for `Date` and a list of checkboxes for `Select`.
+/// Columns without a filter, or with a `Facet` menu shown in their header, get an empty cell.
+/// The row does not hold any state: every change is
+/// reported through `on_filter` with the column ID and the column's new filter (`None` once it
+/// no longer restricts anything).
+///
+/// # Arguments
+/// * `props` - The properties passed to the component.
+///   - `columns` - A `Vec<Column>` whose `filter` fields select the widgets.
+///   - `filters` - The current `ColumnFilters`, shown in the widgets.
+///   - `on_filter` - A `Callback<(&'static str, Option<ColumnFilter>)>` triggered on changes.
+///   - `classes` - A `TableClasses` object defining CSS class names for customization.
+///   - `texts` - A `TableTexts` object providing the widget placeholders.
+///   - `column_window` - The columns to render when they are virtualized; every column when
+///     `None`.
+///
+/// # Returns
+/// (Html): A `<tr>` to render inside the table's `<thead>`.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::filter::{ColumnFilters, FilterKind, TextMatch};
+/// use table_rs::yew::filters::FilterRow;
+/// use table_rs::yew::types::Column;
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let columns = vec![
+///         Column { id: "name", header: "Name", filter: FilterKind::Text(TextMatch::Contains), ..Default::default() },
+///         Column { id: "age", header: "Age", filter: FilterKind::Number, ..Default::default() },
+///     ];
+///     let filters = use_state(ColumnFilters::new);
+///
+///     let on_filter = {
+///         let filters = filters.clone();
+///         Callback::from(move |(id, filter)| {
+///             let mut next = (*filters).clone();
+///             table_rs::core::filter::set_filter(&mut next, id, filter);
+///             filters.set(next);
+///         })
+///     };
+///
+///     html! {
+///         <FilterRow {columns} filters={(*filters).clone()} {on_filter} />
+///     }
+/// }
+/// ```
+#[function_component(FilterRow)]
+pub fn filter_row(props: &FilterRowProps) -> Html {
+    let FilterRowProps {
+        columns,
+        filters,
+        on_filter,
+        classes,
+        texts,
+        column_window,
+    } = props;
+
+    html! {
+        <tr class={classes.filter_row} role="row">
+            { for column_slots(columns, column_window.as_ref()).into_iter().map(|slot| {
+                let (col, style) = match slot {
+                    ColumnSlot::Column(col, style) => (col, style),
+                    ColumnSlot::Spacer(style) => return html! { <th aria-hidden="true" {style} /> },
+                };
+                let col_id = col.id;
+                let current = filters.get(col_id);
+                let emit = {
+                    let on_filter = on_filter.clone();
+                    let kind = col.filter.clone();
+                    let current = current.cloned();
+                    Callback::from(move |input: FilterInput| {
+                        on_filter.emit((col_id, kind.apply(current.as_ref(), input)))
+                    })
+                };
+                let on_input = |make: fn(String) -> FilterInput| {
+                    let emit = emit.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        emit.emit(make(input.value()));
+                    })
+                };
+
+                let widget = match &col.filter {
+                    FilterKind::None | FilterKind::Facet => html! {},
+                    FilterKind::Text(_) => html! {
+                        <input
+                            class={classes.filter_input}
+                            type="text"
+                            value={current.map(|f| f.text().to_string()).unwrap_or_default()}
+                            placeholder={texts.filter_placeholder}
+                            aria-label={format!("Filter {}", col.header)}
+                            oninput={on_input(FilterInput::Text)}
+                        />
+                    },
+                    FilterKind::Number | FilterKind::Date => {
+                        let input_type = if col.filter == FilterKind::Number { "number" } else { "date" };
+                        html! {
+                            <>
+                                <input
+                                    class={classes.filter_input}
+                                    type={input_type}
+                                    value={current.map(|f| f.min_text()).unwrap_or_default()}
+                                    placeholder={texts.filter_min}
+                                    aria-label={format!("{} {}", col.header, texts.filter_min)}
+                                    oninput={on_input(FilterInput::Min)}
+                                />
+                                <input
+                                    class={classes.filter_input}
+                                    type={input_type}
+                                    value={current.map(|f| f.max_text()).unwrap_or_default()}
+                                    placeholder={texts.filter_max}
+                                    aria-label={format!("{} {}", col.header, texts.filter_max)}
+                                    oninput={on_input(FilterInput::Max)}
+                                />
+                            </>
+                        }
+                    }
+                    FilterKind::Select(options) => html! {
+                        { for options.iter().map(|option| {
+                            let option = *option;
+                            let emit = emit.clone();
+                            html! {
+                                <label>
+                                    <input
+                                        class={classes.filter_input}
+                                        type="checkbox"
+                                        checked={current.is_some_and(|f| f.is_selected(option))}
+                                        onchange={Callback::from(move |_| emit.emit(FilterInput::Toggle(option.to_string())))}
+                                    />
+                                    { option }
+                                </label>
+                            }
+                        }) }
+                    },
+                };
+
+                html! {
+                    <th class={classes.filter_cell} {style}>{ widget }</th>
+                }
+            }) }
+        </tr>
+    }
+}
@@ -1,4 +1,10 @@
-use crate::yew::types::{SortOrder, TableHeaderProps};
+use crate::core::filter::{ColumnFilter, FilterInput, FilterKind};
+use crate::core::state::sort_priority;
+use crate::core::virtualize::{ColumnSlot, column_slots};
+use crate::yew::filters::FilterRow;
+use crate::yew::types::FacetMenuProps;
+use crate::yew::types::SortOrder;
+use crate::yew::types::TableHeaderProps;
 use yew::prelude::*;
 
 /// A table header component that renders column headers with optional sorting functionality.
@@ -10,10 +16,25 @@ use yew::prelude::*;
 /// # Arguments
 /// * `props` - The properties passed to the component.
 ///   - `columns` - A list of column definitions (`Vec<Column>`) specifying the headers to render.
-///   - `sort_column` - An `Option<&'static str>` indicating the currently sorted column, if any.
-///   - `sort_order` - A `SortOrder` indicating whether the sort is ascending or descending.
-///   - `on_sort_column` - A `Callback<&'static str>` triggered when a sortable column is clicked.
+///   - `sort` - A `Vec<SortKey>` holding the sort stack, highest priority first.
+///   - `on_sort_column` - A `Callback<(&'static str, bool)>` triggered when a sortable column is
+///     clicked, with the column ID and whether shift was held to add a secondary sort key.
 ///   - `classes` - A `TableClasses` object defining CSS class names for customization.
+///   - `texts` - A `TableTexts` object providing the sort direction indicators and filter placeholders.
+///   - `filters` - The current `ColumnFilters`, shown in the filter row.
+///   - `on_filter` - A `Callback<(&'static str, Option<ColumnFilter>)>` triggered when a filter
+///     widget changes.
+///   - `facets` - The `ColumnFacets` listed by the facet menus.
+///   - `column_window` - The columns to render when they are virtualized, with spacer cells
+///     standing for the others; every column when `None`.
+///
+/// Sortable headers show a sort direction indicator (`texts.sort_ascending`,
+/// `texts.sort_descending` or `texts.sort_unsorted`) in a `<span>` styled by
+/// `classes.sort_indicator`. When several columns are sorted, each sorted header also shows its
+/// priority number in a `<span>` styled by `classes.sort_priority`.
+///
+/// When any column declares a `filter` widget, a [`FilterRow`] is rendered below the headers.
+/// Columns using `FilterKind::Facet` get a [`FacetMenu`] in their header cell instead.
 ///
 /// # Returns
 /// (Html): A rendered `<thead>` element containing the table header row and interactive sorting logic.
@@ -31,15 +52,18 @@ use yew::prelude::*;
 ///         Column { id: "email", header: "Email", sortable: false, ..Default::default() },
 ///     ];
 ///
-///     let sort_order = use_state(|| SortOrder::Asc);
-///     let sort_column = use_state(|| Some("name"));
-///
 ///     let props = TableHeaderProps {
 ///         columns,
-///         sort_column: sort_column,
-///         sort_order: sort_order,
-///         on_sort_column: Callback::from(|col_id| web_sys::console::log_1(&format!("Sort: {}", col_id).into())),
+///         sort: vec![("name", SortOrder::Asc)],
+///         on_sort_column: Callback::from(|(col_id, additive): (&'static str, bool)| {
+///             web_sys::console::log_1(&format!("Sort: {} (shift: {})", col_id, additive).into())
+///         }),
 ///         classes: Default::default(),
+///         texts: Default::default(),
+///         filters: Default::default(),
+///         on_filter: Callback::noop(),
+///         facets: Default::default(),
+///         column_window: None,
 ///     };
 ///    
 ///     html! {
@@ -54,44 +78,209 @@ use yew::prelude::*;
 pub fn header(props: &TableHeaderProps) -> Html {
     let TableHeaderProps {
         columns,
-        sort_column,
-        sort_order,
+        sort,
         on_sort_column,
         classes,
+        texts,
+        filters,
+        on_filter,
+        facets,
+        column_window,
     } = props;
+    let has_filters = columns.iter().any(|col| col.filter.in_filter_row());
 
     html! {
         <thead class={classes.thead}>
             <tr class={classes.row} role="row">
-                { for columns.iter().map(|col| {
+                { for column_slots(columns, column_window.as_ref()).into_iter().map(|slot| {
+                    let (col, slot_style) = match slot {
+                        ColumnSlot::Column(col, style) => (col, style),
+                        ColumnSlot::Spacer(style) => return html! { <th aria-hidden="true" {style} /> },
+                    };
                     let col_id = col.id;
                     let onclick = if col.sortable {
                         let on_sort_column = on_sort_column.clone();
-                        Some(Callback::from(move |_| on_sort_column.emit(col_id)))
+                        Some(Callback::from(move |e: MouseEvent| {
+                            on_sort_column.emit((col_id, e.shift_key()))
+                        }))
                     } else { None };
+                    let priority = sort_priority(sort, col_id);
+                    let indicator = match priority {
+                        Some((_, SortOrder::Asc)) => texts.sort_ascending,
+                        Some((_, SortOrder::Desc)) => texts.sort_descending,
+                        None => texts.sort_unsorted,
+                    };
 
                     html! {
                         <th
                             {onclick}
                             role="columnheader"
                             class={format!("{} {}", classes.header_cell, col.class.unwrap_or("")).trim().to_string()}
-                            style={col.style.unwrap_or_default()}
-                            aria-sort={
-                                if Some(col.id) == **sort_column {
-                                    match **sort_order {
-                                        SortOrder::Asc => "ascending",
-                                        SortOrder::Desc => "descending",
-                                    }
-                                } else {
-                                    "none"
-                                }
-                            }
+                            style={format!("{} {slot_style}", col.style.unwrap_or_default()).trim().to_string()}
+                            aria-sort={priority.map_or("none", |(_, order)| order.aria())}
                         >
                             { col.header }
+                            { if col.sortable && !indicator.is_empty() {
+                                html! {
+                                    <span class={classes.sort_indicator} aria-hidden="true">{ indicator }</span>
+                                }
+                            } else {
+                                html! {}
+                            } }
+                            { match priority {
+                                Some((n, _)) if sort.len() > 1 => html! {
+                                    <span class={classes.sort_priority}>{ n }</span>
+                                },
+                                _ => html! {},
+                            } }
+                            if col.filter == FilterKind::Facet {
+                                <FacetMenu
+                                    column={col_id}
+                                    header={col.header}
+                                    facets={facets.get(col_id).cloned().unwrap_or_default()}
+                                    filter={filters.get(col_id).cloned()}
+                                    on_filter={on_filter.clone()}
+                                    classes={classes.clone()}
+                                    texts={texts.clone()}
+                                />
+                            }
                         </th>
                     }
                 }) }
             </tr>
+            if has_filters {
+                <FilterRow
+                    columns={columns.clone()}
+                    filters={filters.clone()}
+                    on_filter={on_filter.clone()}
+                    classes={classes.clone()}
+                    texts={texts.clone()}
+                    column_window={column_window.clone()}
+                />
+            }
         </thead>
     }
 }
+
+/// An Excel-style dropdown menu listing the distinct values of a column with their row counts.
+///
+/// The menu is opened by a button rendered in the column header. Each value gets a checkbox,
+/// checked while its rows are shown; unchecking it excludes the value through a
+/// `ColumnFilter::NoneOf` filter. A leading "select all" entry shows every value again, or hides
+/// them all. Clicks inside the menu do not sort the column.
+///
+/// # Arguments
+/// * `props` - The properties passed to the component.
+///   - `column` - The ID of the column the menu filters.
+///   - `header` - The header text of the column, used to label the menu.
+///   - `facets` - A `Vec<Facet>` with the distinct values of the column and their row counts.
+///   - `filter` - The current `Option<ColumnFilter>` of the column.
+///   - `on_filter` - A `Callback<(&'static str, Option<ColumnFilter>)>` triggered on changes.
+///   - `classes` - A `TableClasses` object defining CSS class names for customization.
+///   - `texts` - A `TableTexts` object providing the menu labels.
+///
+/// # Returns
+/// (Html): The toggle button, followed by the menu while it is open.
+///
+/// # Examples
+/// ```rust
+/// use table_rs::core::filter::Facet;
+/// use table_rs::yew::header::FacetMenu;
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let facets = vec![
+///         Facet { value: "active".into(), count: 12 },
+///         Facet { value: "archived".into(), count: 3 },
+///     ];
+///
+///     html! {
+///         <FacetMenu column="status" header="Status" {facets} />
+///     }
+/// }
+/// ```
+#[function_component(FacetMenu)]
+pub fn facet_menu(props: &FacetMenuProps) -> Html {
+    let FacetMenuProps {
+        column,
+        header,
+        facets,
+        filter,
+        on_filter,
+        classes,
+        texts,
+    } = props;
+    let column = *column;
+    let open = use_state(|| false);
+
+    let on_toggle = {
+        let open = open.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            open.set(!*open);
+        })
+    };
+    let all_checked = filter.is_none();
+    let on_select_all = {
+        let on_filter = on_filter.clone();
+        let values: Vec<String> = facets.iter().map(|facet| facet.value.clone()).collect();
+        Callback::from(move |_: Event| {
+            let filter = ColumnFilter::NoneOf(values.clone());
+            on_filter.emit((
+                column,
+                (all_checked && filter.is_active()).then_some(filter),
+            ));
+        })
+    };
+
+    html! {
+        <>
+            <button
+                type="button"
+                class={classes.facet_toggle}
+                aria-haspopup="true"
+                aria-expanded={open.to_string()}
+                aria-label={format!("Filter {header}")}
+                onclick={on_toggle}
+            >
+                { texts.facet_toggle }
+            </button>
+            if *open {
+                <div
+                    class={classes.facet_menu}
+                    role="group"
+                    aria-label={format!("Filter {header}")}
+                    onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
+                >
+                    <label class={classes.facet_option}>
+                        <input type="checkbox" checked={all_checked} onchange={on_select_all} />
+                        { texts.facet_select_all }
+                    </label>
+                    { for facets.iter().map(|facet| {
+                        let value = facet.value.clone();
+                        let on_change = {
+                            let on_filter = on_filter.clone();
+                            let filter = filter.clone();
+                            let value = value.clone();
+                            Callback::from(move |_: Event| {
+                                let input = FilterInput::Toggle(value.clone());
+                                on_filter.emit((column, FilterKind::Facet.apply(filter.as_ref(), input)))
+                            })
+                        };
+                        let excluded = filter.as_ref().is_some_and(|f| f.is_excluded(&value));
+                        let label = if value.is_empty() { texts.facet_blank.to_string() } else { value };
+
+                        html! {
+                            <label class={classes.facet_option}>
+                                <input type="checkbox" checked={!excluded} onchange={on_change} />
+                                { label }
+                                <span class={classes.facet_count}>{ facet.count }</span>
+                            </label>
+                        }
+                    }) }
+                </div>
+            }
+        </>
+    }
+}
@@ -1,13 +1,24 @@
 use gloo_timers::callback::Timeout;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
 use web_sys::UrlSearchParams;
 use web_sys::wasm_bindgen::JsValue;
+use yew::platform::spawn_local;
 use yew::prelude::*;
 
+use crate::core::data::SharedRows;
+use crate::core::filter::{ColumnFacets, ColumnFilters, FilterKind, set_filter};
+use crate::core::model::{PipelineColumns, TableModel, total_pages};
+use crate::core::pagination::{clamp_page, refit_page, row_range};
+use crate::core::row::TableRow;
+use crate::core::sort_index::SortCache;
+use crate::core::state::{TableState, toggle_sort_key};
+use crate::core::virtualize::{ColumnWindow, DEFAULT_COLUMN_WIDTH};
 use crate::yew::body::TableBody;
 use crate::yew::controls::PaginationControls;
 use crate::yew::header::TableHeader;
-use crate::yew::types::SortOrder;
-use crate::yew::types::TableProps;
+use crate::yew::types::{SearchMode, TableProps};
 
 /// A fully featured table component with pagination, sorting, and search support.
 ///
@@ -17,20 +28,75 @@ use crate::yew::types::TableProps;
 ///
 /// # Arguments
 /// * `props` - The properties passed to the component.
-///   - `data` - A `Vec<HashMap<&'static str, String>>` representing the table's row data.
+///   - `data` - A `SharedRows<T>` representing the table's row data, where `T: TableRow`
+///     (`HashMap<&'static str, String>` by default, or a struct deriving `TableRow`). A `Vec<T>`
+///     converts into it; data kept across renders is only searched, filtered and sorted again
+///     when replaced.
 ///   - `columns` - A `Vec<Column>` defining the structure and behavior of each column.
 ///   - `page_size` - A `usize` defining how many rows to show per page.
+///   - `page_size_options` - A `Vec<usize>` of page sizes offered in a dropdown (hidden when
+///     empty); `on_page_size_change` is triggered with the selected one.
+///   - `rich_pagination` - A `bool` adding first/last buttons, numbered pages, a "go to page"
+///     input and a "Showing 21–40 of 532" label to the pagination controls.
+///   - `max_sort_keys` - A `usize` capping how many columns can be sorted at once with shift-click.
+///   - `sort_cycle` - A `SortCycle` choosing whether header clicks can return a column to unsorted.
+///   - `collation` - A `Collation` used to compare text when sorting (binary, case-insensitive,
+///     natural or Unicode).
 ///   - `loading` - A `bool` indicating whether the table is in a loading state.
 ///   - `classes` - A `TableClasses` struct for customizing class names of elements.
 ///   - `styles` - A `HashMap<&'static str, &'static str>` for inline style overrides.
 ///   - `paginate` - A `bool` controlling whether pagination controls are displayed.
+///   - `infinite` - A `bool` showing every row instead, ended by a "loading more" row that
+///     triggers `on_load_more` when it scrolls into view, as long as `has_more` is `true`.
+///   - `virtualize` - A `bool` rendering only the rows in view, in a scrolling container
+///     `viewport_height` pixels tall, plus `overscan` rows on each side; rows are `row_height`
+///     pixels tall, or measured once rendered with `measure_rows`.
+///   - `virtualize_columns` - A `bool` rendering only the columns in view, in a scrolling container
+///     `viewport_width` pixels wide, plus `column_overscan` columns on each side; each column is
+///     assumed `min_width` pixels wide (100 when 0), and `pinned` columns are always rendered.
 ///   - `search` - A `bool` enabling a search input above the table.
+///   - `search_mode` - A `SearchMode` choosing between plain text search, the query language
+///     (`status:active age:>30 -name:"test user"`) and fuzzy matching.
+///   - `on_search_error` - A `Callback<QueryError>` triggered when the query becomes invalid; the
+///     search then keeps every row.
+///   - `fuzzy_rank` - A `bool` ordering `SearchMode::Fuzzy` results by score while no column is
+///     sorted.
+///   - `search_debounce_ms` - A `u32` delay after the last keystroke before the search applies
+///     (`300` by default, `0` to search on every keystroke).
+///   - `manual` - A `bool` leaving search, filters, sorting and pagination to the app, which
+///     passes the current page as `data`.
+///   - `total_rows` - A `usize` counting the matching rows across all pages in `manual` mode.
+///   - `has_next` - An `Option<bool>` telling whether a next page exists, for cursor pagination
+///     in `manual` mode where the number of rows is unknown.
+///   - `on_query_change` - A `Callback<TableState>` triggered with the state to fetch in `manual`
+///     mode, on mount and whenever the search, filters, sort or page change.
+///   - `source` - An `Option<SharedSource<T>>` fetching each page asynchronously instead of
+///     reading `data`; failed fetches show an error row with a retry button.
+///   - `filters` - An `Option<ColumnFilters>`; when set, the column filters are controlled by the
+///     parent instead of kept by the table.
+///   - `on_filters_change` - A `Callback<ColumnFilters>` triggered whenever a column filter changes.
+///   - `sort`, `page`, `search_query` - Optional values controlled by the parent instead of kept by
+///     the table (`None` by default).
+///   - `on_sort_change`, `on_page_change`, `on_search_change` - Callbacks triggered with the new
+///     sort keys, page index and search query whenever the user changes them.
 ///   - `texts` - A `TableTexts` struct for customizing placeholder and fallback texts.
 ///
 /// # Features
-/// - **Client-side search** with URL hydration via `?search=`
-/// - **Column sorting** (ascending/descending toggle)
-/// - **Pagination controls**
+/// - **Client-side search** with URL hydration via `?search=`, optionally using a query language
+///   with field-scoped terms, quoted phrases, negation, comparisons and `OR`, or typo-tolerant
+///   fuzzy matching with highlighted matches
+/// - **Column filters** rendered under the headers for columns declaring a `filter` widget,
+///   combined with the search
+/// - **Facet menus** in the headers of `FilterKind::Facet` columns, listing each distinct value
+///   with its row count as the other filters change
+/// - **Column sorting** (ascending/descending toggle with an optional unsorted state, shift-click
+///   to sort by several columns)
+/// - **Pagination controls**, optionally with first/last buttons, numbered pages, a page jump,
+///   a page size selector and the range of rows shown
+/// - **Controlled mode** for the sort, page, search query and filters, reporting every change
+///   to the parent
+/// - **Manual mode** for server-side data, where the app fetches each page on `on_query_change`
+/// - **Async data sources** fetched page by page, with loading and error rows
 /// - **Custom class and inline style support**
 /// - Displays a loading row or empty state message when appropriate
 ///
@@ -41,15 +107,19 @@ use crate::yew::types::TableProps;
 /// ```rust
 /// use yew::prelude::*;
 /// use maplit::hashmap;
+/// use table_rs::core::data::SharedRows;
 /// use table_rs::yew::table::Table;
 /// use table_rs::yew::types::{Column, TableClasses, TableTexts};
 ///
 /// #[function_component(App)]
 /// pub fn app() -> Html {
-///     let data = vec![
-///         hashmap! { "name" => "Ferris".into(), "email" => "ferris@opensass.org".into() },
-///         hashmap! { "name" => "Ferros".into(), "email" => "ferros@opensass.org".into() },
-///     ];
+///     // Kept across renders, so that the table only processes the rows when they change.
+///     let data = use_state(|| {
+///         SharedRows::from(vec![
+///             hashmap! { "name" => "Ferris".into(), "email" => "ferris@opensass.org".into() },
+///             hashmap! { "name" => "Ferros".into(), "email" => "ferros@opensass.org".into() },
+///         ])
+///     });
 ///
 ///     let columns = vec![
 ///         Column { id: "name", header: "Name", sortable: true, ..Default::default() },
@@ -58,7 +128,7 @@ use crate::yew::types::TableProps;
 ///
 ///     html! {
 ///         <Table
-///             data={data}
+///             data={(*data).clone()}
 ///             columns={columns}
 ///             page_size={10}
 ///             loading={false}
@@ -71,36 +141,138 @@ use crate::yew::types::TableProps;
 /// }
 /// ```
 ///
+/// Rows can also be typed structs deriving `TableRow` (requires the `derive` feature):
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # mod example {
+/// use yew::prelude::*;
+/// use table_rs::TableRow;
+/// use table_rs::core::data::SharedRows;
+/// use table_rs::core::row::TableRow as _;
+/// use table_rs::yew::table::Table;
+/// use table_rs::yew::types::Column;
+///
+/// #[derive(Clone, PartialEq, TableRow)]
+/// struct User {
+///     #[table(sortable)]
+///     name: String,
+///     #[table(header = "Email", sortable)]
+///     email: String,
+///     age: u32,
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let data = use_state(|| {
+///         SharedRows::from(vec![
+///             User { name: "Ferris".into(), email: "ferris@opensass.org".into(), age: 12 },
+///             User { name: "Crab".into(), email: "crab@opensass.org".into(), age: 7 },
+///         ])
+///     });
+///     let columns: Vec<Column> = User::columns();
+///
+///     html! {
+///         <Table<User> data={(*data).clone()} columns={columns} paginate={true} />
+///     }
+/// }
+/// # }
+/// ```
+///
 /// # See Also
 /// - [MDN table Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/table)
 #[function_component(Table)]
-pub fn table(props: &TableProps) -> Html {
+pub fn table<T = HashMap<&'static str, String>>(props: &TableProps<T>) -> Html
+where
+    T: TableRow + Clone + PartialEq + 'static,
+{
     let TableProps {
         data,
         columns,
         page_size,
+        page_size_options,
+        on_page_size_change,
+        rich_pagination,
+        max_sort_keys,
+        sort_cycle,
+        collation,
         loading,
         classes,
         styles,
         paginate,
+        infinite,
+        has_more,
+        on_load_more,
+        virtualize,
+        row_height,
+        measure_rows,
+        viewport_height,
+        overscan,
+        virtualize_columns,
+        viewport_width,
+        column_overscan,
         search,
+        search_mode,
+        on_search_error,
+        fuzzy_rank,
+        search_debounce_ms,
+        manual,
+        total_rows,
+        has_next,
+        on_query_change,
+        source,
         texts,
+        filters,
+        on_filters_change,
+        sort,
+        on_sort_change,
+        page,
+        on_page_change,
+        search_query,
+        on_search_change,
     } = props;
 
-    let page = use_state(|| 0);
-    let sort_column = use_state(|| None::<&'static str>);
-    let sort_order = use_state(|| SortOrder::Asc);
-    let search_query = use_state(|| {
+    let page_state = use_state(|| 0);
+    let page = page.unwrap_or(*page_state);
+    let page_size_state = use_state(|| None::<usize>);
+    let page_size = page_size_state.unwrap_or(*page_size);
+    let sort_state = use_state(Vec::new);
+    let sort = sort.clone().unwrap_or_else(|| (*sort_state).clone());
+    let filter_state = use_state(ColumnFilters::new);
+    let filters = filters.clone().unwrap_or_else(|| (*filter_state).clone());
+    let query_state = use_state(|| {
         let window = web_sys::window().unwrap();
         let search_params =
             UrlSearchParams::new_with_str(&window.location().search().unwrap_or_default()).unwrap();
         search_params.get("search").unwrap_or_default()
     });
+    let query = search_query
+        .clone()
+        .unwrap_or_else(|| (*query_state).clone());
+
+    let search_input = use_state(|| query.clone());
+    let pending_search = use_mut_ref(|| None::<Timeout>);
+    {
+        let search_input = search_input.clone();
+        use_effect_with(search_query.clone(), move |search_query| {
+            if let Some(query) = search_query {
+                search_input.set(query.clone());
+            }
+        });
+    }
 
-    let debounced_search = use_state(|| None::<Timeout>);
+    let set_page = {
+        let page_state = page_state.clone();
+        let on_page_change = on_page_change.clone();
+        Callback::from(move |page: usize| {
+            page_state.set(page);
+            on_page_change.emit(page);
+        })
+    };
 
     let update_search_url = {
-        let search_query = search_query.clone();
+        let query_state = query_state.clone();
+        let on_search_change = on_search_change.clone();
+        let set_page = set_page.clone();
         Callback::from(move |query: String| {
             let window = web_sys::window().unwrap();
             let url = window.location().href().unwrap();
@@ -113,82 +285,308 @@ pub fn table(props: &TableProps) -> Html {
                 .unwrap()
                 .replace_state_with_url(&JsValue::NULL, "", Some(&url_obj.href()))
                 .unwrap();
-            search_query.set(query);
+            query_state.set(query.clone());
+            on_search_change.emit(query);
+            set_page.emit(0);
         })
     };
 
-    let on_search_change = {
-        let debounced_search = debounced_search.clone();
+    let on_search_input = {
+        let search_input = search_input.clone();
+        let pending_search = pending_search.clone();
         let update_search_url = update_search_url.clone();
+        let delay = *search_debounce_ms;
         Callback::from(move |e: InputEvent| {
-            let update_search_url = update_search_url.clone();
-            // TODO: Add debounce
-            // let debounced_search_ref = debounced_search.clone();
             let input: web_sys::HtmlInputElement = e.target_unchecked_into();
             let value = input.value();
+            search_input.set(value.clone());
 
-            // let prev_timeout = {
-            //     debounced_search_ref.take()
-            // };
-
-            // if let Some(prev) = prev_timeout {
-            //     prev.cancel();
-            // }
-
-            let timeout = Timeout::new(50, move || {
-                update_search_url.emit(value.clone());
-            });
-
-            debounced_search.set(Some(timeout));
+            // Dropping the pending timeout cancels it.
+            pending_search.borrow_mut().take();
+            if delay == 0 {
+                update_search_url.emit(value);
+                return;
+            }
+            let update_search_url = update_search_url.clone();
+            let timeout = Timeout::new(delay, move || update_search_url.emit(value));
+            *pending_search.borrow_mut() = Some(timeout);
         })
     };
 
-    let mut filtered_rows = data.clone();
-    if !search_query.is_empty() {
-        filtered_rows.retain(|row| {
-            columns.iter().any(|col| {
-                row.get(col.id)
-                    .map(|v| v.to_lowercase().contains(&search_query.to_lowercase()))
-                    .unwrap_or(false)
-            })
-        });
+    let state = TableState {
+        query: query.clone(),
+        search_mode: *search_mode,
+        fuzzy_rank: *fuzzy_rank,
+        filters: filters.clone(),
+        sort: sort.clone(),
+        max_sort_keys: *max_sort_keys,
+        sort_cycle: *sort_cycle,
+        // Infinite tables show every row.
+        page: if *infinite { 0 } else { page },
+        page_size: if *infinite { 0 } else { page_size },
+    };
+    let fetched = use_state(|| None::<(SharedRows<T>, usize, Option<bool>)>);
+    let fetching = use_state_eq(|| false);
+    let fetch_error = use_state(|| None::<String>);
+    let retries = use_state(|| 0_u32);
+    {
+        let fetched = fetched.clone();
+        let fetching = fetching.clone();
+        let fetch_error = fetch_error.clone();
+        use_effect_with(
+            (source.clone(), state.clone(), *retries),
+            move |(source, state, _)| {
+                // Responses to requests superseded by a newer one are dropped.
+                let stale = Rc::new(Cell::new(false));
+                if let Some(source) = source {
+                    let request = source.fetch(state.clone());
+                    let stale = stale.clone();
+                    fetching.set(true);
+                    spawn_local(async move {
+                        let result = request.await;
+                        if stale.get() {
+                            return;
+                        }
+                        match result {
+                            Ok(page) => {
+                                fetched.set(Some((
+                                    page.rows.into(),
+                                    page.total_rows,
+                                    page.has_next,
+                                )));
+                                fetch_error.set(None);
+                            }
+                            Err(error) => fetch_error.set(Some(error)),
+                        }
+                        fetching.set(false);
+                    });
+                } else {
+                    fetching.set(false);
+                }
+                move || stale.set(true)
+            },
+        );
     }
+    let on_retry = {
+        let retries = retries.clone();
+        Callback::from(move |_| retries.set(*retries + 1))
+    };
 
-    if let Some(col_id) = *sort_column {
-        if let Some(col) = columns.iter().find(|c| c.id == col_id) {
-            filtered_rows.sort_by(|a, b| {
-                let val = "".to_string();
-                let a_val = a.get(col.id).unwrap_or(&val);
-                let b_val = b.get(col.id).unwrap_or(&val);
-                match *sort_order {
-                    SortOrder::Asc => a_val.cmp(b_val),
-                    SortOrder::Desc => b_val.cmp(a_val),
-                }
-            });
+    let (rows, paged, total_rows, has_next) = match (source, &*fetched) {
+        (Some(_), Some((rows, total_rows, has_next))) => {
+            (rows.clone(), true, *total_rows, *has_next)
         }
+        (Some(_), None) => (SharedRows::default(), true, 0, None),
+        (None, _) => (data.clone(), *manual, *total_rows, *has_next),
+    };
+    let loading = *loading || *fetching;
+    // Rows are searched, filtered and sorted again only once they or those settings change, not
+    // when paging or scrolling. Pages of manual and async tables are shown as given. Columns only
+    // count through what the pipeline reads of them, so that columns built on every render with
+    // new callbacks do not run it again.
+    let sort_cache = use_mut_ref(|| (SharedRows::default(), SortCache::new()));
+    let facet_columns: Vec<&'static str> = columns
+        .iter()
+        .filter(|col| col.filter == FilterKind::Facet)
+        .map(|col| col.id)
+        .collect();
+    let pipeline = use_memo(
+        (
+            rows.clone(),
+            PipelineColumns::new(columns.clone()),
+            facet_columns,
+            *collation,
+            paged,
+            TableState {
+                page: 0,
+                page_size: 0,
+                ..state.clone()
+            },
+        ),
+        |(rows, columns, facet_columns, collation, paged, state)| {
+            let model = TableModel::new(rows, columns.columns()).with_collation(*collation);
+            let facets: ColumnFacets = facet_columns
+                .iter()
+                .map(|&column| (column, model.facets(column, state)))
+                .collect();
+            if *paged {
+                let search_error = model.manual_view(state, 0).search_error;
+                (None, search_error, facets)
+            } else {
+                let mut sort_cache = sort_cache.borrow_mut();
+                let (sorted, cache) = &mut *sort_cache;
+                // Sort indexes stay valid when rows were only appended, e.g. by infinite scroll.
+                if !rows.extends(sorted) {
+                    cache.clear();
+                }
+                *sorted = rows.clone();
+                let order = model.order_cached(state, cache);
+                (
+                    Some(SharedRows::from(order.indices)),
+                    order.search_error,
+                    facets,
+                )
+            }
+        },
+    );
+    let (order, search_error, facets) = &*pipeline;
+    let view_total_rows = order.as_ref().map_or(total_rows, |order| order.len());
+    let total_pages = total_pages(view_total_rows, state.page_size);
+    let view_page = match order {
+        Some(_) => clamp_page(state.page, total_pages),
+        None => state.page,
+    };
+    let indices = use_memo(
+        (order.clone(), view_page, state.page_size),
+        |(order, page, page_size)| {
+            let order = order.as_ref()?;
+            Some(match row_range(*page, *page_size, order.len()) {
+                Some((1, last)) if last == order.len() => order.clone(),
+                Some((first, last)) => order[first - 1..last].iter().copied().collect(),
+                None => SharedRows::default(),
+            })
+        },
+    );
+    {
+        let on_query_change = on_query_change.clone();
+        let manual = *manual && source.is_none();
+        use_effect_with(state.clone(), move |state| {
+            if manual {
+                on_query_change.emit(state.clone());
+            }
+        });
     }
-
-    let total_pages = (filtered_rows.len() as f64 / *page_size as f64).ceil() as usize;
-    let start = *page * page_size;
-    let end = ((*page + 1) * page_size).min(filtered_rows.len());
-    let page_rows = &filtered_rows[start..end];
+    {
+        let on_search_error = on_search_error.clone();
+        use_effect_with(search_error.clone(), move |error| {
+            if let Some(error) = error {
+                on_search_error.emit(error.clone());
+            }
+        });
+    }
+    // Once the rows are known, fall back to the last page when they no longer reach the current
+    // one, e.g. after `data` shrank. Cursor-based pages have no known last page.
+    let settled = !loading && (source.is_none() || fetched.is_some());
+    let shown_page = match has_next {
+        None if settled => view_page,
+        _ => page,
+    };
+    {
+        let set_page = set_page.clone();
+        use_effect_with((page, shown_page), move |&(page, shown_page)| {
+            if shown_page != page {
+                set_page.emit(shown_page);
+            }
+        });
+    }
+    let page = shown_page;
+    let highlight = match search_mode {
+        SearchMode::Fuzzy => query,
+        _ => String::new(),
+    };
 
     let on_sort_column = {
-        let sort_column = sort_column.clone();
-        let sort_order = sort_order.clone();
-        Callback::from(move |id: &'static str| {
-            if Some(id) == *sort_column {
-                sort_order.set(match *sort_order {
-                    SortOrder::Asc => SortOrder::Desc,
-                    SortOrder::Desc => SortOrder::Asc,
-                });
-            } else {
-                sort_column.set(Some(id));
-                sort_order.set(SortOrder::Asc);
-            }
+        let sort = sort.clone();
+        let sort_state = sort_state.clone();
+        let on_sort_change = on_sort_change.clone();
+        let max_sort_keys = *max_sort_keys;
+        let sort_cycle = *sort_cycle;
+        Callback::from(move |(id, additive): (&'static str, bool)| {
+            let mut keys = sort.clone();
+            toggle_sort_key(&mut keys, id, additive, max_sort_keys, sort_cycle);
+            sort_state.set(keys.clone());
+            on_sort_change.emit(keys);
         })
     };
 
+    let on_page_size = {
+        let set_page = set_page.clone();
+        let on_page_size_change = on_page_size_change.clone();
+        Callback::from(move |size: usize| {
+            set_page.emit(refit_page(page, page_size, size));
+            page_size_state.set(Some(size));
+            on_page_size_change.emit(size);
+        })
+    };
+
+    let on_filter = {
+        let filters = filters.clone();
+        let filter_state = filter_state.clone();
+        let on_filters_change = on_filters_change.clone();
+        let set_page = set_page.clone();
+        Callback::from(move |(id, filter)| {
+            let mut next = filters.clone();
+            set_filter(&mut next, id, filter);
+            filter_state.set(next.clone());
+            on_filters_change.emit(next);
+            set_page.emit(0);
+        })
+    };
+
+    let scroll_top = use_state_eq(|| 0.0);
+    let scroll_left = use_state_eq(|| 0.0);
+    let onscroll = {
+        let scroll_top = scroll_top.clone();
+        let scroll_left = scroll_left.clone();
+        Callback::from(move |e: Event| {
+            let viewport: web_sys::Element = e.target_unchecked_into();
+            scroll_top.set(f64::from(viewport.scroll_top()));
+            scroll_left.set(f64::from(viewport.scroll_left()));
+        })
+    };
+    let column_window = virtualize_columns.then(|| {
+        let widths: Vec<_> = columns
+            .iter()
+            .map(|col| match col.min_width {
+                0 => (DEFAULT_COLUMN_WIDTH, col.pinned),
+                width => (f64::from(width), col.pinned),
+            })
+            .collect();
+        ColumnWindow::new(&widths, *scroll_left, *viewport_width, *column_overscan)
+    });
+    let mut viewport_style = String::new();
+    if *virtualize {
+        viewport_style += &format!("height: {viewport_height}px; overflow-y: auto;");
+    }
+    if *virtualize_columns {
+        viewport_style += &format!(" max-width: {viewport_width}px; overflow-x: auto;");
+    }
+
+    let table = html! {
+        <table class={classes.table} style={*styles.get("table").unwrap_or(&"")} role="table">
+            <TableHeader
+                columns={columns.clone()}
+                {sort}
+                {on_sort_column}
+                classes={classes.clone()}
+                texts={texts.clone()}
+                {filters}
+                {on_filter}
+                facets={facets.clone()}
+                column_window={column_window.clone()}
+            />
+            <TableBody<T>
+                columns={columns.clone()}
+                {rows}
+                indices={(*indices).clone()}
+                {loading}
+                classes={classes.clone()}
+                {highlight}
+                error={(*fetch_error).clone()}
+                {on_retry}
+                has_more={*infinite && *has_more}
+                on_load_more={on_load_more.clone()}
+                virtualize={*virtualize}
+                row_height={*row_height}
+                measure_rows={*measure_rows}
+                overscan={*overscan}
+                scroll_top={*scroll_top}
+                viewport_height={*viewport_height}
+                {column_window}
+            />
+        </table>
+    };
+
     html! {
         <div class={classes.container}>
             { if *search {
@@ -196,33 +594,41 @@ pub fn table(props: &TableProps) -> Html {
                         <input
                             class={classes.search_input}
                             type="text"
-                            value={(*search_query).clone()}
+                            value={(*search_input).clone()}
                             placeholder={texts.search_placeholder}
                             aria-label="Search table"
-                            oninput={on_search_change}
+                            oninput={on_search_input}
                         />
                     }
                 } else {
                     html! {}
                 } }
-            <table class={classes.table} style={*styles.get("table").unwrap_or(&"")} role="table">
-                <TableHeader
-                    columns={columns.clone()}
-                    {sort_column}
-                    {sort_order}
-                    {on_sort_column}
-                    classes={classes.clone()}
-                />
-                <TableBody
-                    columns={columns.clone()}
-                    rows={page_rows.to_vec()}
-                    loading={loading}
-                    classes={classes.clone()}
-                />
-            </table>
-            { if *paginate {
+            if *virtualize || *virtualize_columns {
+                <div
+                    class={classes.viewport}
+                    style={viewport_style.trim().to_string()}
+                    {onscroll}
+                >
+                    { table }
+                </div>
+            } else {
+                { table }
+            }
+            { if *paginate && !*infinite {
                     html! {
-                        <PaginationControls {page} {total_pages} />
+                        <PaginationControls
+                            {page}
+                            on_page_change={set_page}
+                            {total_pages}
+                            {has_next}
+                            rich={*rich_pagination}
+                            {page_size}
+                            page_sizes={page_size_options.clone()}
+                            on_page_size_change={on_page_size}
+                            total_rows={has_next.is_none().then_some(view_total_rows)}
+                            classes={classes.clone()}
+                            texts={texts.clone()}
+                        />
                     }
                 } else {
                     html! {}
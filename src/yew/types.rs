@@ -1,8 +1,23 @@
+use crate::core::compare::{Comparator, SortKeyFn};
+use crate::core::data::SharedRows;
+use crate::core::filter::{ColumnFacets, ColumnFilter, ColumnFilters, Facet, FilterKind};
+use crate::core::model::ColumnSpec;
+use crate::core::query::QueryError;
+use crate::core::row::{ColumnMeta, TableRow};
+use crate::core::source::SharedSource;
+use crate::core::state::{DEFAULT_MAX_SORT_KEYS, TableState};
+use crate::core::value::{CellKind, NullOrder};
+use crate::core::virtualize::{ColumnWindow, DEFAULT_ROW_HEIGHT};
 use std::collections::HashMap;
+use yew::html::{ImplicitClone, IntoPropValue};
 use yew::prelude::*;
 
+pub use crate::core::compare::Collation;
+pub use crate::core::normalize::Normalizer;
+pub use crate::core::state::{SearchMode, SortCycle, SortKey, SortOrder};
+
 /// Represents a column in the table with customization options.
-#[derive(Properties, PartialEq, Clone, Default)]
+#[derive(Properties, PartialEq, Clone)]
 pub struct Column {
     /// Unique identifier for the column.
     #[prop_or("")]
@@ -31,17 +46,117 @@ pub struct Column {
     /// Optional class name(s) for the column header.
     #[prop_or(Some("table-header-cell"))]
     pub class: Option<&'static str>,
+
+    /// How cell values are interpreted when sorting (numbers, dates, booleans...).
+    #[prop_or_default]
+    pub kind: CellKind,
+
+    /// Where empty cells go when sorting, regardless of the sort direction.
+    #[prop_or_default]
+    pub nulls: NullOrder,
+
+    /// Maps cell values to the keys they are sorted by, e.g. to rank severity levels.
+    ///
+    /// Compared by identity: create the columns once (e.g. in a `use_memo`), or the table sorts its
+    /// rows again on every render.
+    #[prop_or_default]
+    pub sort_key: Option<SortKeyFn>,
+
+    /// Custom ordering used instead of the built-in comparison when sorting, e.g. for versions.
+    ///
+    /// Compared by identity: create the columns once (e.g. in a `use_memo`), or the table sorts its
+    /// rows again on every render.
+    #[prop_or_default]
+    pub comparator: Option<Comparator>,
+
+    /// Collation used to compare text when sorting; defaults to the table's `collation`.
+    #[prop_or_default]
+    pub collation: Option<Collation>,
+
+    /// Filter widget rendered for the column in the header's filter row.
+    #[prop_or_default]
+    pub filter: FilterKind,
+
+    /// Whether the global search looks at the column; turn off for ids or timestamps.
+    #[prop_or(true)]
+    pub searchable: bool,
+
+    /// How the plain search normalizes the column's cells and the query, e.g. to ignore accents.
+    #[prop_or_default]
+    pub normalizer: Normalizer,
+
+    /// Whether the column stays rendered and stuck to the left edge when the table's columns are
+    /// virtualized; pinned columns come first.
+    #[prop_or(false)]
+    pub pinned: bool,
 }
 
-/// Sort direction for a column: ascending or descending.
-#[derive(Clone, PartialEq, Default)]
-pub enum SortOrder {
-    /// Ascending order (default).
-    #[default]
-    Asc,
+impl Default for Column {
+    fn default() -> Self {
+        Self {
+            id: "",
+            header: "",
+            accessor: Callback::noop(),
+            sortable: false,
+            min_width: 0,
+            style: None,
+            class: None,
+            kind: CellKind::Auto,
+            nulls: NullOrder::Last,
+            sort_key: None,
+            comparator: None,
+            collation: None,
+            filter: FilterKind::None,
+            searchable: true,
+            normalizer: Normalizer::CaseFold,
+            pinned: false,
+        }
+    }
+}
+
+impl ColumnSpec for Column {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn kind(&self) -> CellKind {
+        self.kind
+    }
+
+    fn nulls(&self) -> NullOrder {
+        self.nulls
+    }
+
+    fn sort_key(&self) -> Option<&SortKeyFn> {
+        self.sort_key.as_ref()
+    }
+
+    fn comparator(&self) -> Option<&Comparator> {
+        self.comparator.as_ref()
+    }
+
+    fn collation(&self) -> Option<Collation> {
+        self.collation
+    }
+
+    fn searchable(&self) -> bool {
+        self.searchable
+    }
 
-    /// Descending order.
-    Desc,
+    fn normalizer(&self) -> Normalizer {
+        self.normalizer
+    }
+}
+
+impl From<ColumnMeta> for Column {
+    fn from(meta: ColumnMeta) -> Self {
+        Self {
+            id: meta.id,
+            header: meta.header,
+            sortable: meta.sortable,
+            ..Default::default()
+        }
+    }
 }
 
 /// Class names used to style various parts of the table.
@@ -91,9 +206,89 @@ pub struct TableClasses {
     #[prop_or("empty-row")]
     pub empty_row: &'static str,
 
+    /// Class name for the row shown when fetching data failed.
+    #[prop_or("error-row")]
+    pub error_row: &'static str,
+
+    /// Class name for the retry button of the error row.
+    #[prop_or("retry-button")]
+    pub retry_button: &'static str,
+
     /// Class name for pagination buttons.
     #[prop_or("pagination-button")]
     pub pagination_button: &'static str,
+
+    /// Class name for the numbered page buttons.
+    #[prop_or("pagination-page")]
+    pub page_button: &'static str,
+
+    /// Class name for the button of the current page, in addition to `page_button`.
+    #[prop_or("pagination-page-active")]
+    pub page_button_active: &'static str,
+
+    /// Class name for the ellipses between page numbers.
+    #[prop_or("pagination-ellipsis")]
+    pub page_ellipsis: &'static str,
+
+    /// Class name for the "go to page" input.
+    #[prop_or("pagination-jump")]
+    pub page_jump: &'static str,
+
+    /// Class name for the page size dropdown.
+    #[prop_or("pagination-size")]
+    pub page_size_select: &'static str,
+
+    /// Class name for the "Showing 21–40 of 532" range label.
+    #[prop_or("pagination-range")]
+    pub pagination_range: &'static str,
+
+    /// Class name for the sort priority number shown in headers when sorting by several columns.
+    #[prop_or("sort-priority")]
+    pub sort_priority: &'static str,
+
+    /// Class name for the sort direction indicator shown in sortable headers.
+    #[prop_or("sort-indicator")]
+    pub sort_indicator: &'static str,
+
+    /// Class name for the header row holding the column filters.
+    #[prop_or("filter-row")]
+    pub filter_row: &'static str,
+
+    /// Class name for the cells of the filter row.
+    #[prop_or("filter-cell")]
+    pub filter_cell: &'static str,
+
+    /// Class name for the inputs of the column filters.
+    #[prop_or("filter-input")]
+    pub filter_input: &'static str,
+
+    /// Class name for the button opening a column's facet menu.
+    #[prop_or("facet-toggle")]
+    pub facet_toggle: &'static str,
+
+    /// Class name for the dropdown listing a column's facets.
+    #[prop_or("facet-menu")]
+    pub facet_menu: &'static str,
+
+    /// Class name for each value of a facet menu.
+    #[prop_or("facet-option")]
+    pub facet_option: &'static str,
+
+    /// Class name for the row count shown next to each facet value.
+    #[prop_or("facet-count")]
+    pub facet_count: &'static str,
+
+    /// Class name for the `<mark>` highlighting fuzzy search matches in cells.
+    #[prop_or("search-highlight")]
+    pub highlight: &'static str,
+
+    /// Class name for the scrolling container of a virtualized table.
+    #[prop_or("table-viewport")]
+    pub viewport: &'static str,
+
+    /// Class name for the rows standing for the rows of a virtualized body that are not rendered.
+    #[prop_or("spacer-row")]
+    pub spacer_row: &'static str,
 }
 
 impl Default for TableClasses {
@@ -110,7 +305,27 @@ impl Default for TableClasses {
             row: "tr",
             loading_row: "loading-row",
             empty_row: "empty-row",
+            error_row: "error-row",
+            retry_button: "retry-button",
             pagination_button: "pagination-button",
+            page_button: "pagination-page",
+            page_button_active: "pagination-page-active",
+            page_ellipsis: "pagination-ellipsis",
+            page_jump: "pagination-jump",
+            page_size_select: "pagination-size",
+            pagination_range: "pagination-range",
+            sort_priority: "sort-priority",
+            sort_indicator: "sort-indicator",
+            filter_row: "filter-row",
+            filter_cell: "filter-cell",
+            filter_input: "filter-input",
+            facet_toggle: "facet-toggle",
+            facet_menu: "facet-menu",
+            facet_option: "facet-option",
+            facet_count: "facet-count",
+            highlight: "search-highlight",
+            viewport: "table-viewport",
+            spacer_row: "spacer-row",
         }
     }
 }
@@ -122,10 +337,22 @@ pub struct TableTexts {
     #[prop_or("Loading...")]
     pub loading: &'static str,
 
+    /// Text of the row shown at the end of an infinite table while more rows are loading.
+    #[prop_or("Loading more...")]
+    pub loading_more: &'static str,
+
     /// Text shown when no rows are found.
     #[prop_or("No results found")]
     pub empty: &'static str,
 
+    /// Text shown when fetching data failed.
+    #[prop_or("Failed to load data.")]
+    pub error: &'static str,
+
+    /// Label of the button fetching the data again after a failure.
+    #[prop_or("Retry")]
+    pub retry: &'static str,
+
     /// Placeholder text for the search input.
     #[prop_or("Search...")]
     pub search_placeholder: &'static str,
@@ -138,30 +365,128 @@ pub struct TableTexts {
     #[prop_or("Next")]
     pub next_button: &'static str,
 
+    /// Label for the "First" pagination button.
+    #[prop_or("First")]
+    pub first_button: &'static str,
+
+    /// Label for the "Last" pagination button.
+    #[prop_or("Last")]
+    pub last_button: &'static str,
+
+    /// Text standing for skipped page numbers.
+    #[prop_or("…")]
+    pub page_ellipsis: &'static str,
+
+    /// Label and placeholder of the "go to page" input.
+    #[prop_or("Go to page")]
+    pub page_jump: &'static str,
+
+    /// Label of the page size dropdown.
+    #[prop_or("Rows per page")]
+    pub page_size_label: &'static str,
+
+    /// Format string for the range of rows shown, e.g., "Showing 21–40 of 532".
+    #[prop_or("Showing {start}–{end} of {total}")]
+    pub range_indicator: &'static str,
+
     /// Format string for the page indicator, e.g., "Page 1 of 5".
     #[prop_or("Page {current} of {total}")]
     pub page_indicator: &'static str,
+
+    /// Format string for the page indicator of cursor pagination, where the total is unknown.
+    #[prop_or("Page {current}")]
+    pub cursor_indicator: &'static str,
+
+    /// Indicator shown in the header of a column sorted in ascending order.
+    #[prop_or("▲")]
+    pub sort_ascending: &'static str,
+
+    /// Indicator shown in the header of a column sorted in descending order.
+    #[prop_or("▼")]
+    pub sort_descending: &'static str,
+
+    /// Indicator shown in the header of a sortable column that is not sorted; hidden when empty.
+    #[prop_or("")]
+    pub sort_unsorted: &'static str,
+
+    /// Placeholder text for the text column filters.
+    #[prop_or("Filter...")]
+    pub filter_placeholder: &'static str,
+
+    /// Placeholder text for the lower bound of number column filters.
+    #[prop_or("Min")]
+    pub filter_min: &'static str,
+
+    /// Placeholder text for the upper bound of number column filters.
+    #[prop_or("Max")]
+    pub filter_max: &'static str,
+
+    /// Label of the button opening a column's facet menu.
+    #[prop_or("▾")]
+    pub facet_toggle: &'static str,
+
+    /// Label of the facet menu entry checking or unchecking every value.
+    #[prop_or("(Select all)")]
+    pub facet_select_all: &'static str,
+
+    /// Label of the facet value standing for empty cells.
+    #[prop_or("(Blanks)")]
+    pub facet_blank: &'static str,
 }
 
 impl Default for TableTexts {
     fn default() -> Self {
         Self {
             loading: "Loading...",
+            loading_more: "Loading more...",
             empty: "No results found",
+            error: "Failed to load data.",
+            retry: "Retry",
             search_placeholder: "Search...",
             previous_button: "Previous",
             next_button: "Next",
+            first_button: "First",
+            last_button: "Last",
+            page_ellipsis: "…",
+            page_jump: "Go to page",
+            page_size_label: "Rows per page",
+            range_indicator: "Showing {start}–{end} of {total}",
             page_indicator: "Page {current} of {total}",
+            cursor_indicator: "Page {current}",
+            sort_ascending: "▲",
+            sort_descending: "▼",
+            sort_unsorted: "",
+            filter_placeholder: "Filter...",
+            filter_min: "Min",
+            filter_max: "Max",
+            facet_toggle: "▾",
+            facet_select_all: "(Select all)",
+            facet_blank: "(Blanks)",
         }
     }
 }
 
+impl<T> ImplicitClone for SharedRows<T> {}
+
+impl<T> IntoPropValue<SharedRows<T>> for Vec<T> {
+    fn into_prop_value(self) -> SharedRows<T> {
+        self.into()
+    }
+}
+
 /// Props for the main table component.
 #[derive(Properties, PartialEq, Clone)]
-pub struct TableProps {
-    /// Vector of row data as key-value pairs.
+pub struct TableProps<T = HashMap<&'static str, String>>
+where
+    T: TableRow + Clone + PartialEq + 'static,
+{
+    /// Row data; string maps by default, or any type implementing `TableRow`.
+    ///
+    /// Accepts a `Vec<T>`, or a [`SharedRows<T>`] kept across renders (e.g. in a `use_state`):
+    /// data is compared by identity, and the rows are only searched, filtered and sorted again
+    /// once it is replaced.
     #[prop_or_default]
-    pub data: Vec<HashMap<&'static str, String>>,
+    pub data: SharedRows<T>,
 
     /// List of column definitions.
     #[prop_or_default]
@@ -171,6 +496,32 @@ pub struct TableProps {
     #[prop_or(10)]
     pub page_size: usize,
 
+    /// Page sizes offered in a dropdown of the pagination controls; hidden when empty.
+    #[prop_or_default]
+    pub page_size_options: Vec<usize>,
+
+    /// Callback triggered with the page size the user selects.
+    #[prop_or(Callback::noop())]
+    pub on_page_size_change: Callback<usize>,
+
+    /// Whether the pagination controls add first/last buttons, numbered pages, a "go to page"
+    /// input and the range of rows shown.
+    #[prop_or(false)]
+    pub rich_pagination: bool,
+
+    /// Maximum number of columns sorted at once with shift-click.
+    #[prop_or(DEFAULT_MAX_SORT_KEYS)]
+    pub max_sort_keys: usize,
+
+    /// How repeated header clicks cycle through sort states; `SortCycle::TriState` adds an
+    /// unsorted state after descending.
+    #[prop_or_default]
+    pub sort_cycle: SortCycle,
+
+    /// Collation used to compare text when sorting columns that do not set their own.
+    #[prop_or_default]
+    pub collation: Collation,
+
     /// Whether the table is currently in a loading state.
     #[prop_or(false)]
     pub loading: bool,
@@ -187,10 +538,132 @@ pub struct TableProps {
     #[prop_or(false)]
     pub paginate: bool,
 
+    /// Whether to show every row and ask for more through `on_load_more` when the end of the
+    /// table scrolls into view, instead of paginating.
+    #[prop_or(false)]
+    pub infinite: bool,
+
+    /// Whether more rows can be loaded in `infinite` mode; set to `false` at the end of the data.
+    #[prop_or(true)]
+    pub has_more: bool,
+
+    /// Callback triggered when the end of an `infinite` table scrolls into view.
+    #[prop_or(Callback::noop())]
+    pub on_load_more: Callback<()>,
+
+    /// Whether to render only the rows in view, in a scrolling container `viewport_height` pixels
+    /// tall, instead of every row.
+    #[prop_or(false)]
+    pub virtualize: bool,
+
+    /// Height of each row of a virtualized table, in pixels, or their estimate with
+    /// `measure_rows`.
+    #[prop_or(DEFAULT_ROW_HEIGHT)]
+    pub row_height: f64,
+
+    /// Whether rows of a virtualized table have variable heights, measured once rendered.
+    #[prop_or(false)]
+    pub measure_rows: bool,
+
+    /// Height of the scrolling container of a virtualized table, in pixels.
+    #[prop_or(480.0)]
+    pub viewport_height: f64,
+
+    /// Number of rows rendered above and below the viewport of a virtualized table.
+    #[prop_or(5)]
+    pub overscan: usize,
+
+    /// Whether to render only the columns in view, in a scrolling container `viewport_width`
+    /// pixels wide, instead of every column; each column is assumed `min_width` pixels wide.
+    #[prop_or(false)]
+    pub virtualize_columns: bool,
+
+    /// Width of the scrolling container of a table with virtualized columns, in pixels.
+    #[prop_or(960.0)]
+    pub viewport_width: f64,
+
+    /// Number of columns rendered left and right of the viewport when columns are virtualized.
+    #[prop_or(2)]
+    pub column_overscan: usize,
+
     /// Whether to enable search functionality.
     #[prop_or(false)]
     pub search: bool,
 
+    /// Whether the search input takes plain text, the query language or fuzzy matching.
+    #[prop_or_default]
+    pub search_mode: SearchMode,
+
+    /// Callback triggered when the search query becomes invalid in `SearchMode::Query`.
+    #[prop_or(Callback::noop())]
+    pub on_search_error: Callback<QueryError>,
+
+    /// Whether `SearchMode::Fuzzy` results are ordered by score while no column is sorted.
+    #[prop_or(false)]
+    pub fuzzy_rank: bool,
+
+    /// Milliseconds the search waits after the last keystroke before filtering; `0` filters on
+    /// every keystroke.
+    #[prop_or(300)]
+    pub search_debounce_ms: u32,
+
+    /// Whether the data is searched, filtered, sorted and paginated by the app, e.g. on a server:
+    /// `data` is then rendered as the current page, as is.
+    #[prop_or(false)]
+    pub manual: bool,
+
+    /// Number of rows matching the search and filters across all pages, in `manual` mode.
+    #[prop_or(0)]
+    pub total_rows: usize,
+
+    /// Whether a next page exists, for cursor pagination in `manual` mode where the number of
+    /// rows is unknown; when set, `total_rows` is ignored and the page indicator has no total.
+    #[prop_or_default]
+    pub has_next: Option<bool>,
+
+    /// Callback triggered with the search, filters, sort and page to fetch, in `manual` mode; also
+    /// triggered once on mount.
+    #[prop_or(Callback::noop())]
+    pub on_query_change: Callback<TableState>,
+
+    /// Source fetching the rows page by page whenever the search, filters, sort or page change;
+    /// when set, `data`, `manual` and `total_rows` are ignored.
+    #[prop_or_default]
+    pub source: Option<SharedSource<T>>,
+
+    /// Column filters to apply; when set, the table is controlled and only renders these filters.
+    #[prop_or_default]
+    pub filters: Option<ColumnFilters>,
+
+    /// Callback triggered with the new column filters whenever a filter widget changes.
+    #[prop_or(Callback::noop())]
+    pub on_filters_change: Callback<ColumnFilters>,
+
+    /// Sort keys to apply; when set, the table is controlled and only renders this sort.
+    #[prop_or_default]
+    pub sort: Option<Vec<SortKey>>,
+
+    /// Callback triggered with the new sort keys whenever a header is clicked.
+    #[prop_or(Callback::noop())]
+    pub on_sort_change: Callback<Vec<SortKey>>,
+
+    /// Zero-based page to show; when set, the table is controlled and only renders this page.
+    #[prop_or_default]
+    pub page: Option<usize>,
+
+    /// Callback triggered with the new page index whenever the table changes page, including
+    /// the return to the first page after the search or the filters change.
+    #[prop_or(Callback::noop())]
+    pub on_page_change: Callback<usize>,
+
+    /// Search query to apply; when set, the table is controlled and only renders this query.
+    #[prop_or_default]
+    pub search_query: Option<String>,
+
+    /// Callback triggered with the new search query once the user stops typing.
+    #[prop_or(Callback::noop())]
+    pub on_search_change: Callback<String>,
+
     /// Text labels for the table UI.
     #[prop_or_default]
     pub texts: TableTexts,
@@ -203,19 +676,34 @@ pub struct HeaderProps {
     #[prop_or_default]
     pub columns: Vec<Column>,
 
-    /// Current column used for sorting.
-    pub sort_column: UseStateHandle<Option<&'static str>>,
+    /// Current sort stack, highest priority first.
+    #[prop_or_default]
+    pub sort: Vec<SortKey>,
+
+    /// Callback triggered when a column header is clicked for sorting, with the column ID and
+    /// whether shift was held to add or toggle a secondary sort key.
+    #[prop_or(Callback::noop())]
+    pub on_sort_column: Callback<(&'static str, bool)>,
 
-    /// Current sort order (ascending/descending).
-    pub sort_order: UseStateHandle<SortOrder>,
+    /// Current column filters, shown in the filter row.
+    #[prop_or_default]
+    pub filters: ColumnFilters,
 
-    /// Callback triggered when a column header is clicked for sorting.
+    /// Callback triggered when a filter widget changes, with the column ID and its new filter.
     #[prop_or(Callback::noop())]
-    pub on_sort_column: Callback<&'static str>,
+    pub on_filter: Callback<(&'static str, Option<ColumnFilter>)>,
+
+    /// Facets of the columns using a facet menu, listed in their header.
+    #[prop_or_default]
+    pub facets: ColumnFacets,
 
     /// CSS classes used to style the header.
     #[prop_or_default]
     pub classes: TableClasses,
+
+    /// Texts used in the header, such as the sort direction indicators.
+    #[prop_or_default]
+    pub texts: TableTexts,
 }
 
 /// Alias for `HeaderProps` to be used explicitly in header components.
@@ -225,31 +713,143 @@ pub struct TableHeaderProps {
     #[prop_or_default]
     pub columns: Vec<Column>,
 
-    /// Current column used for sorting.
-    pub sort_column: UseStateHandle<Option<&'static str>>,
+    /// Current sort stack, highest priority first.
+    #[prop_or_default]
+    pub sort: Vec<SortKey>,
 
-    /// Current sort order (ascending/descending).
-    pub sort_order: UseStateHandle<SortOrder>,
+    /// Callback triggered when a column is sorted, with the column ID and whether shift was held
+    /// to add or toggle a secondary sort key.
+    #[prop_or(Callback::noop())]
+    pub on_sort_column: Callback<(&'static str, bool)>,
 
-    /// Callback triggered when a column is sorted.
+    /// Current column filters, shown in the filter row.
+    #[prop_or_default]
+    pub filters: ColumnFilters,
+
+    /// Callback triggered when a filter widget changes, with the column ID and its new filter.
     #[prop_or(Callback::noop())]
-    pub on_sort_column: Callback<&'static str>,
+    pub on_filter: Callback<(&'static str, Option<ColumnFilter>)>,
+
+    /// Facets of the columns using a facet menu, listed in their header.
+    #[prop_or_default]
+    pub facets: ColumnFacets,
 
     /// CSS classes used to style the table header.
     #[prop_or_default]
     pub classes: TableClasses,
+
+    /// Texts used in the header, such as the sort direction indicators.
+    #[prop_or_default]
+    pub texts: TableTexts,
+
+    /// Columns to render when they are virtualized, with spacer cells standing for the others;
+    /// every column when `None`.
+    #[prop_or_default]
+    pub column_window: Option<ColumnWindow>,
+}
+
+/// Props for the row of column filter widgets.
+#[derive(Properties, PartialEq, Clone)]
+pub struct FilterRowProps {
+    /// Column definitions; each column's `filter` selects its widget.
+    #[prop_or_default]
+    pub columns: Vec<Column>,
+
+    /// Current column filters.
+    #[prop_or_default]
+    pub filters: ColumnFilters,
+
+    /// Callback triggered when a filter widget changes, with the column ID and its new filter.
+    #[prop_or(Callback::noop())]
+    pub on_filter: Callback<(&'static str, Option<ColumnFilter>)>,
+
+    /// CSS classes used to style the filter row.
+    #[prop_or_default]
+    pub classes: TableClasses,
+
+    /// Texts used in the filter widgets.
+    #[prop_or_default]
+    pub texts: TableTexts,
+
+    /// Columns to render when they are virtualized, with spacer cells standing for the others;
+    /// every column when `None`.
+    #[prop_or_default]
+    pub column_window: Option<ColumnWindow>,
+}
+
+/// Props for the facet menu of a column header.
+#[derive(Properties, PartialEq, Clone)]
+pub struct FacetMenuProps {
+    /// ID of the column the menu filters.
+    #[prop_or("")]
+    pub column: &'static str,
+
+    /// Header text of the column, used to label the menu.
+    #[prop_or("")]
+    pub header: &'static str,
+
+    /// Distinct values of the column with their row counts.
+    #[prop_or_default]
+    pub facets: Vec<Facet>,
+
+    /// Current filter of the column, if any.
+    #[prop_or_default]
+    pub filter: Option<ColumnFilter>,
+
+    /// Callback triggered when a value is checked or unchecked, with the column ID and its new
+    /// filter.
+    #[prop_or(Callback::noop())]
+    pub on_filter: Callback<(&'static str, Option<ColumnFilter>)>,
+
+    /// CSS classes used to style the menu.
+    #[prop_or_default]
+    pub classes: TableClasses,
+
+    /// Texts used in the menu.
+    #[prop_or_default]
+    pub texts: TableTexts,
 }
 
 /// Props for the pagination controls component.
 #[derive(Properties, PartialEq, Clone)]
 pub struct PaginationControlsProps {
     /// Current page index.
-    pub page: UseStateHandle<usize>,
+    pub page: usize,
+
+    /// Callback triggered with the page index the user navigates to.
+    #[prop_or(Callback::noop())]
+    pub on_page_change: Callback<usize>,
 
     /// Total number of pages.
     #[prop_or(1)]
     pub total_pages: usize,
 
+    /// Whether a next page exists, for cursor pagination where the total is unknown; when set,
+    /// `total_pages` is ignored.
+    #[prop_or_default]
+    pub has_next: Option<bool>,
+
+    /// Whether to render first/last buttons, numbered pages, a "go to page" input and the range
+    /// of rows shown, in addition to the previous/next buttons.
+    #[prop_or(false)]
+    pub rich: bool,
+
+    /// Number of rows per page, selected in the page size dropdown.
+    #[prop_or(10)]
+    pub page_size: usize,
+
+    /// Page sizes offered in the page size dropdown; the dropdown is hidden when empty.
+    #[prop_or_default]
+    pub page_sizes: Vec<usize>,
+
+    /// Callback triggered with the page size the user selects.
+    #[prop_or(Callback::noop())]
+    pub on_page_size_change: Callback<usize>,
+
+    /// Number of rows across all pages, shown in the range label; unknown when `None`.
+    #[prop_or_default]
+    pub total_rows: Option<usize>,
+
     /// Class names used to style pagination elements.
     #[prop_or_default]
     pub classes: TableClasses,
@@ -261,14 +861,21 @@ pub struct PaginationControlsProps {
 
 /// Props for rendering the body of the table.
 #[derive(Properties, PartialEq, Clone)]
-pub struct TableBodyProps {
+pub struct TableBodyProps<T = HashMap<&'static str, String>>
+where
+    T: TableRow + Clone + PartialEq + 'static,
+{
     /// Column definitions.
     #[prop_or_default]
     pub columns: Vec<Column>,
 
-    /// List of row data to render.
+    /// Row data to render; accepts a `Vec<T>` or a [`SharedRows<T>`].
     #[prop_or_default]
-    pub rows: Vec<HashMap<&'static str, String>>,
+    pub rows: SharedRows<T>,
+
+    /// Indices into `rows` of the rows to render, in order; every row when `None`.
+    #[prop_or_default]
+    pub indices: Option<SharedRows<usize>>,
 
     /// Indicates if the body is in a loading state.
     #[prop_or(false)]
@@ -281,4 +888,53 @@ pub struct TableBodyProps {
     /// Text labels used in the body (e.g., loading, empty).
     #[prop_or_default]
     pub texts: TableTexts,
+
+    /// Fuzzy search query whose matches are highlighted in the cells; empty for none.
+    #[prop_or_default]
+    pub highlight: String,
+
+    /// Message of the last failed fetch; when set, an error row replaces the rows.
+    #[prop_or_default]
+    pub error: Option<String>,
+
+    /// Callback triggered when the retry button of the error row is clicked.
+    #[prop_or(Callback::noop())]
+    pub on_retry: Callback<()>,
+
+    /// Whether to end the rows with a loading row that triggers `on_load_more` once visible.
+    #[prop_or(false)]
+    pub has_more: bool,
+
+    /// Callback triggered when the loading row ending the rows scrolls into view.
+    #[prop_or(Callback::noop())]
+    pub on_load_more: Callback<()>,
+
+    /// Whether to render only the rows in view, with spacer rows standing for the others.
+    #[prop_or(false)]
+    pub virtualize: bool,
+
+    /// Height of each row when virtualized, in pixels, or their estimate with `measure_rows`.
+    #[prop_or(DEFAULT_ROW_HEIGHT)]
+    pub row_height: f64,
+
+    /// Whether virtualized rows have variable heights, measured once rendered.
+    #[prop_or(false)]
+    pub measure_rows: bool,
+
+    /// Number of rows rendered above and below the viewport when virtualized.
+    #[prop_or(5)]
+    pub overscan: usize,
+
+    /// Distance the scrolling container is scrolled down from the top of the body, in pixels.
+    #[prop_or(0.0)]
+    pub scroll_top: f64,
+
+    /// Height of the scrolling container, in pixels.
+    #[prop_or(480.0)]
+    pub viewport_height: f64,
+
+    /// Columns to render when they are virtualized, with spacer cells standing for the others;
+    /// every column when `None`.
+    #[prop_or_default]
+    pub column_window: Option<ColumnWindow>,
 }